@@ -12,11 +12,69 @@ The main functions are:
 
 from .binary import (
     binary_f1_score,
+    binary_fbeta_score,
     binary_precision,
     binary_recall,
     binary_stats,
     binary_tp_fp_fn,
 )
+from .bootstrap import binary_bootstrap_ci, bootstrap_ci
+from .boundary import boundary_f1, boundary_iou, boundary_mask
+from .boxes import (
+    box_iou,
+    complete_box_iou,
+    distance_box_iou,
+    generalized_box_iou,
+)
+from .classification_report import classification_report
+from .compare import mcnemar_test, paired_bootstrap_test, paired_permutation_test
 from .confusion_matrix import confusion_matrix
-from .iou import iou
-from .multiclass import f1_score, precision, recall, stats
+from .correlation import kendall_tau, pearsonr, spearmanr
+from .descriptive import DescriptiveStats, describe
+from .detection import detection_map
+from .dice import dice_score, generalized_dice
+from .instance import instance_stats, label_components
+from .intervals import proportion_ci
+from .iou import iou, jaccard_score, mean_iou
+from .keypoints import keypoint_oks, keypoint_pck
+from .multiclass import f1_score, fbeta_score, precision, recall, stats
+from .multilabel import (
+    hamming_loss,
+    multilabel_confusion_matrix,
+    multilabel_f1_score,
+    multilabel_precision,
+    multilabel_recall,
+    multilabel_tp_fp_fn_tn,
+    subset_accuracy,
+)
+from .nms import batched_nms, nms, soft_nms
+from .panoptic import panoptic_quality
+from .probabilistic import (
+    brier_score_loss,
+    calibration_curve,
+    expected_calibration_error,
+    log_loss,
+    maximum_calibration_error,
+)
+from .quantiles import Histogram, TDigest, histogram, histogram_bin_edges, quantile
+from .ranking import (
+    hit_rate,
+    mean_average_precision,
+    mean_reciprocal_rank,
+    ndcg_score,
+    precision_at_k,
+    ranking_stats,
+    recall_at_k,
+)
+from .regression import (
+    explained_variance_score,
+    mean_absolute_error,
+    mean_absolute_percentage_error,
+    mean_squared_error,
+    median_absolute_error,
+    r2_score,
+    regression_stats,
+    root_mean_squared_error,
+)
+from .surface import hausdorff_distance, mean_surface_distance
+from .topk import top_k_accuracy, top_k_recall
@@ -1,4 +1,4 @@
-from typing import List, Set, Tuple, Union
+from typing import List, Optional, Set, Tuple, Union
 
 import numpy as np
 
@@ -11,6 +11,10 @@ def _binary_recall_reqs(
 def _binary_f1_score_reqs(
     actual: np.ndarray, pred: np.ndarray
 ) -> Tuple[int, int, int]: ...
+def _binary_f1_score_reqs_batch(actual: np.ndarray, pred: np.ndarray) -> np.ndarray: ...
+def _binary_f1_score_reqs_grouped(
+    actual: np.ndarray, pred: np.ndarray, groups: np.ndarray, n_groups: int
+) -> np.ndarray: ...
 def _precision(
     actual: np.ndarray, pred: np.ndarray, labels: Union[List, np.ndarray]
 ) -> np.ndarray: ...
@@ -23,4 +27,132 @@ def _f1_score(
 def _confusion_matrix(
     actual: np.ndarray, pred: np.ndarray, labels: Union[List, np.ndarray]
 ) -> np.ndarray: ...
+def _multilabel_reqs(actual: np.ndarray, pred: np.ndarray) -> np.ndarray: ...
+def _multilabel_sample_reqs(actual: np.ndarray, pred: np.ndarray) -> np.ndarray: ...
+def _top_k_reqs(indices: np.ndarray, scores: np.ndarray, k: int) -> np.ndarray: ...
+def _confusion_matrix_batch(
+    actual: np.ndarray, pred: np.ndarray, labels: Union[List, np.ndarray]
+) -> np.ndarray: ...
+def _confusion_matrix_grouped(
+    actual: np.ndarray,
+    pred: np.ndarray,
+    labels: Union[List, np.ndarray],
+    groups: np.ndarray,
+    n_groups: int,
+) -> np.ndarray: ...
+def _confusion_matrix_scores(
+    actual: np.ndarray,
+    labels: Union[List, np.ndarray],
+    scores: np.ndarray,
+    class_axis: int,
+) -> np.ndarray: ...
+def _boundary(mask: np.ndarray) -> np.ndarray: ...
+def _boundary_f1_reqs(
+    actual: np.ndarray, pred: np.ndarray, tolerance: float
+) -> Tuple[int, int, int, int]: ...
+def _boundary_iou_reqs(
+    actual: np.ndarray, pred: np.ndarray, dilation: float
+) -> Tuple[int, int]: ...
+def _surface_distances(
+    actual: np.ndarray, pred: np.ndarray, spacing: List[float]
+) -> Tuple[np.ndarray, np.ndarray]: ...
+def _label_components(mask: np.ndarray, full: bool) -> Tuple[np.ndarray, int]: ...
+def _instance_reqs(
+    actual: np.ndarray, pred: np.ndarray, iou_threshold: float, full: bool
+) -> Tuple[int, int, int]: ...
+def _panoptic_reqs(
+    true_instances: np.ndarray,
+    true_classes: np.ndarray,
+    pred_instances: np.ndarray,
+    pred_classes: np.ndarray,
+    labels: np.ndarray,
+    void_label: Optional[int],
+) -> np.ndarray: ...
+def _box_iou(boxes_a: np.ndarray, boxes_b: np.ndarray, kind: str) -> np.ndarray: ...
+def _nms(
+    boxes: np.ndarray,
+    scores: np.ndarray,
+    labels: Optional[np.ndarray],
+    iou_threshold: float,
+) -> np.ndarray: ...
+def _soft_nms(
+    boxes: np.ndarray,
+    scores: np.ndarray,
+    iou_threshold: float,
+    sigma: float,
+    score_threshold: float,
+    linear: bool,
+) -> Tuple[np.ndarray, np.ndarray]: ...
+def _detection_eval(
+    gt_boxes: np.ndarray,
+    gt_keys: np.ndarray,
+    gt_areas: np.ndarray,
+    gt_crowd: np.ndarray,
+    dt_boxes: np.ndarray,
+    dt_keys: np.ndarray,
+    dt_scores: np.ndarray,
+    iou_thresholds: List[float],
+    area_ranges: List[Tuple[float, float]],
+    max_dets: List[int],
+) -> Tuple[np.ndarray, np.ndarray]: ...
+def _oks_reqs(
+    actual: np.ndarray, pred: np.ndarray, areas: np.ndarray, sigmas: np.ndarray
+) -> np.ndarray: ...
+def _pck_reqs(
+    actual: np.ndarray, pred: np.ndarray, thresholds: np.ndarray
+) -> np.ndarray: ...
+def _moments(arr: np.ndarray, axis: Optional[int]) -> np.ndarray: ...
+def _merge_moments(a: np.ndarray, b: np.ndarray) -> np.ndarray: ...
+def _quantile(
+    arr: np.ndarray, qs: List[float], axis: Optional[int], method: str
+) -> np.ndarray: ...
+def _tdigest_update(
+    centroids: np.ndarray, arr: np.ndarray, compression: float
+) -> Tuple[np.ndarray, float, float]: ...
+def _tdigest_merge(a: np.ndarray, b: np.ndarray, compression: float) -> np.ndarray: ...
+def _tdigest_quantile(
+    centroids: np.ndarray, min: float, max: float, qs: List[float]
+) -> np.ndarray: ...
+def _histogram(arr: np.ndarray, edges: List[float]) -> np.ndarray: ...
+def _correlation(x: np.ndarray, y: np.ndarray, method: str) -> np.ndarray: ...
+def _log_loss_sum(actual: np.ndarray, prob: np.ndarray, eps: float) -> float: ...
+def _brier_sum(actual: np.ndarray, prob: np.ndarray) -> float: ...
+def _calibration_reqs(
+    actual: np.ndarray, prob: np.ndarray, edges: List[float]
+) -> np.ndarray: ...
+def _bootstrap_totals(
+    counts: np.ndarray, multiplicity: np.ndarray, n_boot: int, seed: int
+) -> np.ndarray: ...
+def _bootstrap_interval(
+    replicates: np.ndarray,
+    jackknife: Optional[np.ndarray],
+    estimate: float,
+    confidence: float,
+) -> Tuple[float, float]: ...
+def _proportion_interval(
+    successes: np.ndarray, trials: np.ndarray, confidence: float, method: str
+) -> np.ndarray: ...
+def _paired_counts(
+    actual: np.ndarray,
+    pred_a: np.ndarray,
+    pred_b: np.ndarray,
+    labels: Union[List, np.ndarray],
+) -> np.ndarray: ...
+def _paired_permutation_reqs(
+    counts: np.ndarray, n_permutations: int, seed: int
+) -> np.ndarray: ...
+def _mcnemar(n01: int, n10: int, exact: bool) -> Tuple[float, float]: ...
+def _ranking_reqs(
+    relevance: np.ndarray, scores: np.ndarray, k: Optional[int]
+) -> np.ndarray: ...
+def _ranking_reqs_grouped(
+    relevance: np.ndarray,
+    scores: np.ndarray,
+    query_ids: np.ndarray,
+    k: Optional[int],
+) -> Tuple[np.ndarray, np.ndarray]: ...
+def _regression_reqs(
+    actual: np.ndarray, pred: np.ndarray, weights: Optional[np.ndarray]
+) -> np.ndarray: ...
 def _unique(arr: np.ndarray) -> Set: ...
+def _label_indices(arr: np.ndarray, labels: Union[List, np.ndarray]) -> np.ndarray: ...
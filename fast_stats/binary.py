@@ -1,16 +1,20 @@
 from enum import Enum
-from typing import Dict, Tuple, Union
+from typing import Any, Dict, Optional, Tuple, Union
 
 import numpy as np
 
 from ._fast_stats_ext import (
     _binary_f1_score_reqs,
+    _binary_f1_score_reqs_grouped,
     _binary_precision_reqs,
     _binary_recall_reqs,
 )
+from .confusion_matrix import _group_indices
 from .exceptions import ShapeError
+from .intervals import Interval, IntervalType, _interval
 
 Result = Union[None, float]
+Stats = Dict[str, Union[Result, Interval]]
 
 
 class ZeroDivision(Enum):
@@ -136,17 +140,21 @@ def binary_f1_score(
     return 2 * p * r / (p + r)  # type: ignore
 
 
-def binary_tp_fp_fn(
+def binary_fbeta_score(
     y_true: np.ndarray,
     y_pred: np.ndarray,
-) -> Tuple[int, int, int]:
-    """Binary calculations for TP, FP, and FN
+    beta: float,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+) -> Result:
+    """Binary calculation for f-beta score
 
     Args:
         y_true (np.ndarray): array of true values (must be bool or int types)
         y_pred (np.ndarray): array of pred values (must be bool or int types)
+        beta (float): weight of recall relative to precision (must be >= 0)
+        zero_division (str): determines how to handle division by zero
     Returns:
-        Tuple[int]: counts for TP, FP, and FN
+        Result: None or float depending on values and zero division
     """
     if not all(
         [
@@ -157,24 +165,35 @@ def binary_tp_fp_fn(
         raise TypeError("y_true and y_pred must be numpy arrays")
     if y_true.shape != y_pred.shape:
         raise ShapeError("y_true and y_pred must be same shape")
+    if beta < 0:
+        raise ValueError("beta must be >= 0")
+    zero_division = ZeroDivision(zero_division)
 
     tp, tp_fp, tp_fn = _binary_f1_score_reqs(y_true, y_pred)
-    fp, fn = tp_fp - tp, tp_fn - tp
-    return tp, fp, fn
+    p, r = _precision(tp, tp_fp, ZeroDivision.ZERO), _recall(
+        tp, tp_fn, ZeroDivision.ZERO
+    )
 
+    beta2 = beta**2
+    if beta2 * p + r == 0:  # type: ignore
+        if zero_division == ZeroDivision.NONE:
+            return None
+        elif zero_division == ZeroDivision.ZERO:
+            return 0.0
+    return (1 + beta2) * p * r / (beta2 * p + r)  # type: ignore
 
-def binary_stats(
+
+def binary_tp_fp_fn(
     y_true: np.ndarray,
     y_pred: np.ndarray,
-    zero_division: ZeroDivision = ZeroDivision.NONE,
-) -> Dict[str, Result]:
-    """Binary calculations for precision, recall and f1-score
+) -> Tuple[int, int, int]:
+    """Binary calculations for TP, FP, and FN
 
     Args:
         y_true (np.ndarray): array of true values (must be bool or int types)
         y_pred (np.ndarray): array of pred values (must be bool or int types)
     Returns:
-        Dict[str, Result]: stats for precision, recall and f1-score
+        Tuple[int]: counts for TP, FP, and FN
     """
     if not all(
         [
@@ -185,11 +204,23 @@ def binary_stats(
         raise TypeError("y_true and y_pred must be numpy arrays")
     if y_true.shape != y_pred.shape:
         raise ShapeError("y_true and y_pred must be same shape")
-    zero_division = ZeroDivision(zero_division)
 
     tp, tp_fp, tp_fn = _binary_f1_score_reqs(y_true, y_pred)
+    fp, fn = tp_fp - tp, tp_fn - tp
+    return tp, fp, fn
+
+
+def _binary_stats(
+    tp: int,
+    tp_fp: int,
+    tp_fn: int,
+    n: int,
+    zero_division: ZeroDivision,
+    ci: Optional[IntervalType],
+    confidence: float,
+) -> Stats:
     p, r = _precision(tp, tp_fp, zero_division), _recall(tp, tp_fn, zero_division)
-    stats = dict({"precision": p, "recall": r})
+    stats: Stats = dict({"precision": p, "recall": r})
 
     # convert p and/or r to 0 if None
     if p is None:
@@ -208,4 +239,69 @@ def binary_stats(
 
     stats.update({"f1-score": f1})
 
+    if ci is not None:
+        # accuracy counts TP and TN out of every value
+        correct = n - tp_fp - tp_fn + 2 * tp
+        bounds = _interval(
+            np.array([tp, tp, correct]), np.array([tp_fp, tp_fn, n]), ci, confidence
+        )
+        stats.update(
+            {
+                "accuracy": _recall(correct, n, zero_division),
+                "precision_ci": tuple(bounds[0].tolist()),
+                "recall_ci": tuple(bounds[1].tolist()),
+                "accuracy_ci": tuple(bounds[2].tolist()),
+            }
+        )
+
     return stats
+
+
+def binary_stats(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    ci: Optional[IntervalType] = None,
+    confidence: float = 0.95,
+    groups: Optional[np.ndarray] = None,
+) -> Union[Stats, Dict[Any, Stats]]:
+    """Binary calculations for precision, recall and f1-score
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        zero_division (str): determines how to handle division by zero
+        ci (optional | str): "wilson", "clopper_pearson" or "agresti_coull" to
+            add accuracy and (low, high) intervals of precision, recall and accuracy
+        confidence (optional | float): confidence level of the intervals
+        groups (optional | np.ndarray): group id (e.g. site or patient) of every
+            entry of the leading axes of y_true
+    Returns:
+        Dict[str, Result]: stats for precision, recall and f1-score, or a dict of
+            these stats per group id when groups is given
+    """
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    zero_division = ZeroDivision(zero_division)
+    if ci is not None:
+        ci = IntervalType(ci)
+
+    if groups is not None:
+        ids, inverse = _group_indices(groups, y_true.shape)
+        x = _binary_f1_score_reqs_grouped(y_true, y_pred, inverse, len(ids))
+        per_entry = y_true.size // max(inverse.size, 1)
+        n = np.bincount(inverse, minlength=len(ids)) * per_entry
+        return {
+            g: _binary_stats(*x[i].tolist(), n[i].item(), zero_division, ci, confidence)
+            for i, g in enumerate(ids.tolist())
+        }
+
+    tp, tp_fp, tp_fn = _binary_f1_score_reqs(y_true, y_pred)
+    return _binary_stats(tp, tp_fp, tp_fn, y_true.size, zero_division, ci, confidence)
@@ -0,0 +1,272 @@
+from enum import Enum
+from typing import Dict, List, Optional, Tuple, Union
+
+import numpy as np
+
+from ._fast_stats_ext import (
+    _binary_f1_score_reqs,
+    _binary_f1_score_reqs_batch,
+    _bootstrap_interval,
+    _bootstrap_totals,
+    _confusion_matrix,
+    _confusion_matrix_batch,
+    _unique,
+)
+from .exceptions import ShapeError
+from .multiclass import AverageType, Result, ZeroDivision, _get_zero_handler
+
+
+class BootstrapMetric(Enum):
+    PRECISION = "precision"
+    RECALL = "recall"
+    F1 = "f1"
+    IOU = "iou"
+
+
+class IntervalMethod(Enum):
+    PERCENTILE = "percentile"
+    BCA = "bca"
+
+
+def _check_params(n_boot: int, confidence: float, seed: int) -> None:
+    if n_boot < 1:
+        raise ValueError("n_boot must be >= 1")
+    if not 0 < confidence < 1:
+        raise ValueError("confidence must be in (0, 1)")
+    if seed < 0:
+        raise ValueError("seed must be >= 0")
+
+
+def _group_units(x: np.ndarray, groups: np.ndarray) -> Tuple[np.ndarray, np.ndarray]:
+    # sum the (N, C, 3) per-sample counts of every group, each group is one unit
+    if not isinstance(groups, np.ndarray) or groups.shape != (x.shape[0],):
+        raise ShapeError("groups must be a 1D array with one entry per sample")
+    _, inverse = np.unique(groups, return_inverse=True)
+    units = np.zeros((inverse.max() + 1,) + x.shape[1:], dtype=np.int64)
+    np.add.at(units, inverse.ravel(), x)
+    return units, np.ones(len(units), dtype=np.int64)
+
+
+def _metric(
+    x: np.ndarray,
+    metric: BootstrapMetric,
+    average: AverageType,
+    zero_division: ZeroDivision,
+) -> np.ndarray:
+    # x is (..., C, 3) of TP, TP + FP and TP + FN
+    x = x.astype(np.float64)
+    if average == AverageType.MICRO:
+        x = x.sum(axis=-2, keepdims=True)
+    tp, tp_fp, tp_fn = x[..., 0], x[..., 1], x[..., 2]
+    zero_handle = _get_zero_handler(zero_division)
+    with np.errstate(divide="ignore", invalid="ignore"):
+        if metric == BootstrapMetric.PRECISION:
+            score = zero_handle(tp / tp_fp)
+        elif metric == BootstrapMetric.RECALL:
+            score = zero_handle(tp / tp_fn)
+        elif metric == BootstrapMetric.F1:
+            score = zero_handle(2 * tp / (tp_fp + tp_fn))
+        else:
+            score = zero_handle(tp / (tp_fp + tp_fn - tp))
+    if average == AverageType.MACRO:
+        return np.nanmean(score, axis=-1)
+    if average == AverageType.MICRO:
+        return score[..., 0]
+    return score
+
+
+def _bootstrap(
+    units: np.ndarray,
+    multiplicity: np.ndarray,
+    metric: BootstrapMetric,
+    average: AverageType,
+    zero_division: ZeroDivision,
+    n_boot: int,
+    confidence: float,
+    method: IntervalMethod,
+    seed: int,
+) -> Dict[str, Result]:
+    # units is (K, C, 3) counts of every distinct unit repeated `multiplicity` times
+    n_classes = units.shape[1]
+    counts = np.ascontiguousarray(units.reshape(len(units), -1), dtype=np.int64)
+    multiplicity = np.ascontiguousarray(multiplicity, dtype=np.int64)
+    total = (counts * multiplicity[:, np.newaxis]).sum(axis=0)
+
+    def score(x: np.ndarray) -> np.ndarray:
+        res = _metric(x.reshape(-1, n_classes, 3), metric, average, zero_division)
+        return res.reshape(len(res), -1)
+
+    estimate = score(total)[0]
+    replicates = score(_bootstrap_totals(counts, multiplicity, n_boot, seed))
+    if method == IntervalMethod.BCA:
+        # leaving out one unit of a type gives the same value for all its copies
+        present = multiplicity > 0
+        jackknife = score(total - counts[present])
+        weights = multiplicity[present].astype(np.float64)
+
+    low, high = np.empty(len(estimate)), np.empty(len(estimate))
+    for c in range(len(estimate)):
+        jack = None
+        if method == IntervalMethod.BCA:
+            jack = np.stack([jackknife[:, c], weights], axis=1)
+        low[c], high[c] = _bootstrap_interval(
+            np.ascontiguousarray(replicates[:, c]), jack, estimate[c], confidence
+        )
+
+    if average == AverageType.NONE:
+        return {"estimate": estimate, "low": low, "high": high}
+    return {"estimate": estimate.item(), "low": low.item(), "high": high.item()}
+
+
+def binary_bootstrap_ci(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    metric: BootstrapMetric = BootstrapMetric.F1,
+    groups: Optional[np.ndarray] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    n_boot: int = 1000,
+    confidence: float = 0.95,
+    method: IntervalMethod = IntervalMethod.PERCENTILE,
+    seed: int = 0,
+) -> Dict[str, Result]:
+    """Bootstrap confidence interval of a binary metric, resampling single values
+    or whole groups of samples (e.g. all pixels of an image)
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        metric (optional | str): "precision", "recall", "f1" or "iou"
+        groups (optional | np.ndarray): group id of every entry of the leading
+            axis, groups are resampled instead of single values
+        zero_division (optional | str): strategy to handle division by 0,
+            undefined replicates are left out of the interval
+        n_boot (optional | int): number of bootstrap replicates
+        confidence (optional | float): confidence level of the interval
+        method (optional | str): "percentile" or bias corrected and accelerated
+            "bca" interval
+        seed (optional | int): seed of the random generator
+    Returns:
+        Dict[str, Result]: estimate, low and high bounds of the interval
+    """
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    metric = BootstrapMetric(metric)
+    zero_division = ZeroDivision(zero_division)
+    method = IntervalMethod(method)
+    _check_params(n_boot, confidence, seed)
+
+    if groups is not None:
+        x = _binary_f1_score_reqs_batch(y_true, y_pred)
+        units, multiplicity = _group_units(x[:, np.newaxis], groups)
+    else:
+        # every value is one of 4 unit types: TP, FP, FN and TN
+        tp, tp_fp, tp_fn = _binary_f1_score_reqs(y_true, y_pred)
+        units = np.array([[[1, 1, 1]], [[0, 1, 0]], [[0, 0, 1]], [[0, 0, 0]]])
+        multiplicity = np.array(
+            [tp, tp_fp - tp, tp_fn - tp, y_true.size - tp_fp - tp_fn + tp]
+        )
+    return _bootstrap(
+        units,
+        multiplicity,
+        metric,
+        AverageType.MICRO,
+        zero_division,
+        n_boot,
+        confidence,
+        method,
+        seed,
+    )
+
+
+def bootstrap_ci(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    metric: BootstrapMetric = BootstrapMetric.F1,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    average: AverageType = AverageType.MACRO,
+    groups: Optional[np.ndarray] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    n_boot: int = 1000,
+    confidence: float = 0.95,
+    method: IntervalMethod = IntervalMethod.PERCENTILE,
+    seed: int = 0,
+) -> Dict[str, Result]:
+    """Bootstrap confidence interval of a multi-class metric, resampling single
+    values or whole groups of samples (e.g. all pixels of an image)
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        metric (optional | str): "precision", "recall", "f1" or "iou"
+        labels (optional | list or np.ndarray):
+            labels to calculate the metric for (must be bool or int types)
+        average (optional | str): strategy for averaging across classes
+        groups (optional | np.ndarray): group id of every entry of the leading
+            axis, groups are resampled instead of single values
+        zero_division (optional | str): strategy to handle division by 0,
+            undefined replicates are left out of the interval
+        n_boot (optional | int): number of bootstrap replicates
+        confidence (optional | float): confidence level of the interval
+        method (optional | str): "percentile" or bias corrected and accelerated
+            "bca" interval
+        seed (optional | int): seed of the random generator
+    Returns:
+        Dict[str, Result]: estimate, low and high bounds of the interval, 1D arrays
+            per class when average is "none"
+    """
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    metric = BootstrapMetric(metric)
+    average = AverageType(average)
+    zero_division = ZeroDivision(zero_division)
+    method = IntervalMethod(method)
+    _check_params(n_boot, confidence, seed)
+
+    if labels is None:
+        labels = np.array(
+            sorted(list(_unique(y_true).union(_unique(y_pred)))), dtype=y_true.dtype
+        )
+    elif isinstance(labels, list):
+        labels = np.array(labels, dtype=y_true.dtype)
+    n_classes = len(labels)
+
+    if groups is not None:
+        cm = _confusion_matrix_batch(y_true, y_pred, labels)
+        x = np.stack(
+            [np.diagonal(cm, axis1=1, axis2=2), cm.sum(axis=1), cm.sum(axis=2)],
+            axis=-1,
+        )
+        units, multiplicity = _group_units(x, groups)
+    else:
+        # every value is one of the confusion matrix cells (true i, pred j)
+        cm = _confusion_matrix(y_true, y_pred, labels)
+        eye = np.eye(n_classes, dtype=np.int64)
+        true, pred = eye[:, np.newaxis], eye[np.newaxis]
+        units = np.stack(np.broadcast_arrays(true * pred, pred, true), axis=-1)
+        units = units.reshape(-1, n_classes, 3)
+        multiplicity = cm.ravel()
+    return _bootstrap(
+        units,
+        multiplicity,
+        metric,
+        average,
+        zero_division,
+        n_boot,
+        confidence,
+        method,
+        seed,
+    )
@@ -0,0 +1,102 @@
+import numpy as np
+
+from ._fast_stats_ext import _boundary, _boundary_f1_reqs, _boundary_iou_reqs
+from .exceptions import ShapeError
+from .multiclass import Result, ZeroDivision
+
+
+def boundary_mask(mask: np.ndarray) -> np.ndarray:
+    """Boundary of a binary mask: foreground elements with a background (or out of
+    bounds) neighbour, 4-connected in 2-D and 6-connected in 3-D
+
+    Args:
+        mask (np.ndarray): array of 0/1 values (must be bool or int types)
+    Returns:
+        boundary (np.ndarray): bool array of the same shape
+    """
+    if not isinstance(mask, np.ndarray):
+        raise TypeError("mask must be a numpy array")
+    return _boundary(mask)
+
+
+def boundary_f1(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    tolerance_px: float = 2.0,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+) -> Result:
+    """Calculation of boundary F1 (BF score) for binary masks: boundary elements
+    within tolerance_px (Euclidean) of the other boundary count as matched
+
+    Args:
+        y_true (np.ndarray): array of 0/1 values (must be bool or int types)
+        y_pred (np.ndarray): array of 0/1 values (must be bool or int types)
+        tolerance_px (optional | float): match distance in pixels
+        zero_division (optional | str): strategy to handle division by 0
+    Returns:
+        Result: None or float depending on values and zero division
+    """
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    if tolerance_px < 0:
+        raise ValueError("tolerance_px must be >= 0")
+    zero_division = ZeroDivision(zero_division)
+
+    pred_matched, pred_total, true_matched, true_total = _boundary_f1_reqs(
+        y_true, y_pred, float(tolerance_px)
+    )
+    p = pred_matched / pred_total if pred_total > 0 else 0.0
+    r = true_matched / true_total if true_total > 0 else 0.0
+
+    if p + r == 0:
+        if zero_division == ZeroDivision.NONE:
+            return None
+        elif zero_division == ZeroDivision.ZERO:
+            return 0.0
+    return 2 * p * r / (p + r)
+
+
+def boundary_iou(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    dilation_px: float = 2.0,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+) -> Result:
+    """Calculation of boundary IoU for binary masks: IoU of the mask elements within
+    dilation_px (Euclidean) of each mask's contour
+
+    Args:
+        y_true (np.ndarray): array of 0/1 values (must be bool or int types)
+        y_pred (np.ndarray): array of 0/1 values (must be bool or int types)
+        dilation_px (optional | float): width of the contour band in pixels
+        zero_division (optional | str): strategy to handle division by 0
+    Returns:
+        Result: None or float depending on values and zero division
+    """
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    if dilation_px < 0:
+        raise ValueError("dilation_px must be >= 0")
+    zero_division = ZeroDivision(zero_division)
+
+    intersection, union = _boundary_iou_reqs(y_true, y_pred, float(dilation_px))
+    if union == 0:
+        if zero_division == ZeroDivision.NONE:
+            return None
+        elif zero_division == ZeroDivision.ZERO:
+            return 0.0
+    return intersection / union
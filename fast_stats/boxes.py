@@ -0,0 +1,111 @@
+from enum import Enum
+from typing import Tuple
+
+import numpy as np
+
+from ._fast_stats_ext import _box_iou
+from .exceptions import ShapeError
+
+
+class BoxFormat(Enum):
+    XYXY = "xyxy"
+    XYWH = "xywh"
+    CXCYWH = "cxcywh"
+
+
+def _to_xyxy(boxes: np.ndarray, box_format: BoxFormat) -> np.ndarray:
+    if box_format == BoxFormat.XYXY:
+        return boxes
+    if box_format == BoxFormat.XYWH:
+        xy, wh = boxes[:, :2], boxes[:, 2:]
+        return np.concatenate([xy, xy + wh], axis=1)
+    cxcy, half_wh = boxes[:, :2], boxes[:, 2:] / 2
+    return np.concatenate([cxcy - half_wh, cxcy + half_wh], axis=1)
+
+
+def _as_boxes(
+    boxes_a: np.ndarray, boxes_b: np.ndarray, box_format: BoxFormat
+) -> Tuple[np.ndarray, np.ndarray]:
+    if not all(
+        [
+            isinstance(boxes_a, np.ndarray),
+            isinstance(boxes_b, np.ndarray),
+        ]
+    ):
+        raise TypeError("boxes_a and boxes_b must be numpy arrays")
+    if boxes_a.ndim != 2 or boxes_a.shape[1] != 4:
+        raise ShapeError("boxes_a must have shape (N, 4)")
+    if boxes_b.ndim != 2 or boxes_b.shape[1] != 4:
+        raise ShapeError("boxes_b must have shape (M, 4)")
+    box_format = BoxFormat(box_format)
+
+    # keep float32 boxes as they are, everything else is computed in float64
+    dtype = np.result_type(boxes_a, boxes_b, np.float32)
+    if dtype != np.float32:
+        dtype = np.float64
+    boxes_a = _to_xyxy(np.ascontiguousarray(boxes_a, dtype=dtype), box_format)
+    boxes_b = _to_xyxy(np.ascontiguousarray(boxes_b, dtype=dtype), box_format)
+    return boxes_a, boxes_b
+
+
+def box_iou(
+    boxes_a: np.ndarray, boxes_b: np.ndarray, format: BoxFormat = BoxFormat.XYXY
+) -> np.ndarray:
+    """Calculation of the pairwise IoU of two sets of boxes
+
+    Args:
+        boxes_a (np.ndarray): (N, 4) array of boxes
+        boxes_b (np.ndarray): (M, 4) array of boxes
+        format (optional | str): box coordinates as "xyxy", "xywh" or "cxcywh"
+    Returns:
+        iou (np.ndarray): (N, M) array of IoUs
+    """
+    return _box_iou(*_as_boxes(boxes_a, boxes_b, format), "iou")
+
+
+def generalized_box_iou(
+    boxes_a: np.ndarray, boxes_b: np.ndarray, format: BoxFormat = BoxFormat.XYXY
+) -> np.ndarray:
+    """Calculation of the pairwise generalized IoU (GIoU) of two sets of boxes, the
+    IoU minus the fraction of the enclosing box not covered by the union
+
+    Args:
+        boxes_a (np.ndarray): (N, 4) array of boxes
+        boxes_b (np.ndarray): (M, 4) array of boxes
+        format (optional | str): box coordinates as "xyxy", "xywh" or "cxcywh"
+    Returns:
+        giou (np.ndarray): (N, M) array of GIoUs in [-1, 1]
+    """
+    return _box_iou(*_as_boxes(boxes_a, boxes_b, format), "giou")
+
+
+def distance_box_iou(
+    boxes_a: np.ndarray, boxes_b: np.ndarray, format: BoxFormat = BoxFormat.XYXY
+) -> np.ndarray:
+    """Calculation of the pairwise distance IoU (DIoU) of two sets of boxes, the
+    IoU minus the squared center distance over the squared enclosing box diagonal
+
+    Args:
+        boxes_a (np.ndarray): (N, 4) array of boxes
+        boxes_b (np.ndarray): (M, 4) array of boxes
+        format (optional | str): box coordinates as "xyxy", "xywh" or "cxcywh"
+    Returns:
+        diou (np.ndarray): (N, M) array of DIoUs in [-1, 1]
+    """
+    return _box_iou(*_as_boxes(boxes_a, boxes_b, format), "diou")
+
+
+def complete_box_iou(
+    boxes_a: np.ndarray, boxes_b: np.ndarray, format: BoxFormat = BoxFormat.XYXY
+) -> np.ndarray:
+    """Calculation of the pairwise complete IoU (CIoU) of two sets of boxes, the
+    DIoU with an extra aspect ratio consistency penalty
+
+    Args:
+        boxes_a (np.ndarray): (N, 4) array of boxes
+        boxes_b (np.ndarray): (M, 4) array of boxes
+        format (optional | str): box coordinates as "xyxy", "xywh" or "cxcywh"
+    Returns:
+        ciou (np.ndarray): (N, M) array of CIoUs
+    """
+    return _box_iou(*_as_boxes(boxes_a, boxes_b, format), "ciou")
@@ -0,0 +1,130 @@
+from typing import Dict, List, Optional, Union
+
+import numpy as np
+
+from ._fast_stats_ext import _confusion_matrix, _unique
+from .exceptions import ShapeError
+from .multiclass import ZeroDivision, _get_zero_handler
+
+HEADERS = ["precision", "recall", "f1-score", "support"]
+
+
+def _weighted_mean(x: np.ndarray, weights: np.ndarray) -> float:
+    mask = np.isfinite(x)
+    if weights[mask].sum() == 0:
+        return np.nan
+    return np.average(x[mask], weights=weights[mask]).item()
+
+
+def classification_report(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    target_names: Optional[List[str]] = None,
+    output_dict: bool = False,
+    digits: int = 2,
+    zero_division: ZeroDivision = ZeroDivision.ZERO,
+) -> Union[str, Dict[str, Union[float, Dict[str, float]]]]:
+    """Classification report of precision, recall, f1-score and support computed
+    from a single confusion matrix (matches `sklearn.metrics.classification_report`)
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        labels (optional | list or np.ndarray):
+            labels to include in the report (must be bool or int types)
+        target_names (optional | list): display names matching the labels
+        output_dict (optional | bool): return a dictionary instead of text
+        digits (optional | int): number of digits for formatting floats
+        zero_division (optional | str): strategy to handle division by 0
+    Returns:
+        report (str or dict): text summary or nested dictionary of the stats
+    """
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    zero_division = ZeroDivision(zero_division)
+
+    present = _unique(y_true).union(_unique(y_pred))
+    if labels is None:
+        labels = np.array(sorted(list(present)), dtype=y_true.dtype)
+        micro_is_accuracy = True
+    else:
+        labels = np.array(labels, dtype=y_true.dtype)
+        micro_is_accuracy = set(labels.tolist()) >= present
+
+    if target_names is None:
+        target_names = ["%s" % label for label in labels.tolist()]
+    elif len(target_names) != len(labels):
+        raise ValueError("target_names must be the same length as labels")
+
+    # marginals are taken over every present label so subsets match sklearn
+    all_labels = np.array(
+        sorted(list(present.union(labels.tolist()))), dtype=y_true.dtype
+    )
+    idx = np.searchsorted(all_labels, labels)
+    cm = _confusion_matrix(y_true, y_pred, all_labels)
+    tp, tp_fp, tp_fn = np.diag(cm)[idx], cm.sum(axis=0)[idx], cm.sum(axis=1)[idx]
+    zero_handle = _get_zero_handler(zero_division)
+
+    def f1_from_ext(x, y, z):
+        p, r = x / y, x / z
+        return 2 * p * r / (p + r)
+
+    with np.errstate(divide="ignore", invalid="ignore"):
+        p = zero_handle(tp / tp_fp)
+        r = zero_handle(tp / tp_fn)
+        f1 = zero_handle(f1_from_ext(tp, tp_fp, tp_fn))
+        micro = [
+            zero_handle(tp.sum() / tp_fp.sum()).item(),
+            zero_handle(tp.sum() / tp_fn.sum()).item(),
+            zero_handle(f1_from_ext(tp.sum(), tp_fp.sum(), tp_fn.sum())).item(),
+        ]
+        macro = [np.nanmean(p).item(), np.nanmean(r).item(), np.nanmean(f1).item()]
+    weighted = [zero_handle(_weighted_mean(x, tp_fn)).item() for x in (p, r, f1)]
+    support = int(tp_fn.sum())
+
+    rows = [
+        (name, p[i].item(), r[i].item(), f1[i].item(), int(tp_fn[i]))
+        for i, name in enumerate(target_names)
+    ]
+    averages = [
+        ("accuracy" if micro_is_accuracy else "micro avg", micro),
+        ("macro avg", macro),
+        ("weighted avg", weighted),
+    ]
+
+    if output_dict:
+        report: Dict[str, Union[float, Dict[str, float]]] = dict()
+        for row in rows:
+            report.update({row[0]: dict(zip(HEADERS, row[1:]))})
+        for heading, avg in averages:
+            if heading == "accuracy":
+                report.update({heading: avg[2]})
+            else:
+                report.update({heading: dict(zip(HEADERS, [*avg, support]))})
+        return report
+
+    name_width = max([len(name) for name in target_names])
+    width = max(name_width, len("weighted avg"), digits)
+    head_fmt = "{:>{width}s} " + " {:>9}" * len(HEADERS)
+    row_fmt = "{:>{width}s} " + " {:>9.{digits}f}" * 3 + " {:>9}\n"
+    text = head_fmt.format("", *HEADERS, width=width) + "\n\n"
+    for row in rows:
+        text += row_fmt.format(*row, width=width, digits=digits)
+    text += "\n"
+    for heading, avg in averages:
+        if heading == "accuracy":
+            accuracy_fmt = "{:>{width}s} " + " {:>9}" * 2 + " {:>9.{digits}f} {:>9}\n"
+            text += accuracy_fmt.format(
+                heading, "", "", avg[2], support, width=width, digits=digits
+            )
+        else:
+            text += row_fmt.format(heading, *avg, support, width=width, digits=digits)
+    return text
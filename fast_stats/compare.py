@@ -0,0 +1,257 @@
+from typing import Dict, List, Optional, Tuple, Union
+
+import numpy as np
+
+from ._fast_stats_ext import (
+    _bootstrap_totals,
+    _mcnemar,
+    _paired_counts,
+    _paired_permutation_reqs,
+    _unique,
+)
+from .bootstrap import BootstrapMetric, _metric
+from .exceptions import ShapeError
+from .multiclass import AverageType, Result, ZeroDivision
+
+
+def _paired_counts_from_ext(
+    y_true: np.ndarray,
+    y_pred_a: np.ndarray,
+    y_pred_b: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]],
+) -> Tuple[np.ndarray, np.ndarray]:
+    if not all(
+        [
+            isinstance(y_true, np.ndarray),
+            isinstance(y_pred_a, np.ndarray),
+            isinstance(y_pred_b, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true, y_pred_a and y_pred_b must be numpy arrays")
+    if not y_true.shape == y_pred_a.shape == y_pred_b.shape:
+        raise ShapeError("y_true, y_pred_a and y_pred_b must be same shape")
+
+    if labels is None:
+        labels = np.array(
+            sorted(list(_unique(y_true).union(_unique(y_pred_a), _unique(y_pred_b)))),
+            dtype=y_true.dtype,
+        )
+    elif isinstance(labels, list):
+        labels = np.array(labels, dtype=y_true.dtype)
+    return _paired_counts(y_true, y_pred_a, y_pred_b, labels), labels
+
+
+def _units(counts: np.ndarray) -> Tuple[np.ndarray, np.ndarray]:
+    # every occupied (true, a, b) cell is a unit type with (2, C, 3) requirements
+    t, a, b = np.nonzero(counts)
+    eye = np.eye(counts.shape[0], dtype=np.int64)
+    units = np.stack(
+        [np.stack([eye[t] * eye[p], eye[p], eye[t]], axis=-1) for p in (a, b)],
+        axis=1,
+    )
+    return units, counts[t, a, b]
+
+
+def _difference(
+    x: np.ndarray,
+    metric: BootstrapMetric,
+    average: AverageType,
+    zero_division: ZeroDivision,
+    pos_index: Optional[int],
+) -> np.ndarray:
+    # x is (..., 2, C, 3) requirements of models A and B
+    if pos_index is not None:
+        x, average = x[..., [pos_index], :], AverageType.MICRO
+    a = _metric(x[..., 0, :, :], metric, average, zero_division)
+    b = _metric(x[..., 1, :, :], metric, average, zero_division)
+    return a - b
+
+
+def _pos_index(labels: np.ndarray, pos_label: Optional[int]) -> Optional[int]:
+    if pos_label is None:
+        return None
+    index = np.flatnonzero(labels == pos_label)
+    if len(index) == 0:
+        raise ValueError("pos_label must be one of the labels")
+    return index[0].item()
+
+
+def _result(
+    statistic: np.ndarray, p_value: np.ndarray, effect_size: np.ndarray
+) -> Dict[str, Result]:
+    if np.ndim(effect_size) == 0:
+        return {
+            "statistic": np.asarray(statistic).item(),
+            "p_value": np.asarray(p_value).item(),
+            "effect_size": np.asarray(effect_size).item(),
+        }
+    return {"statistic": statistic, "p_value": p_value, "effect_size": effect_size}
+
+
+def _p_value(null: np.ndarray, observed: np.ndarray) -> np.ndarray:
+    # share of the null differences at least as extreme, counting the observed one
+    with np.errstate(invalid="ignore"):
+        extreme = (np.abs(null) >= np.abs(observed) - 1e-12).sum(axis=0)
+    p_value = (1 + extreme) / (1 + (~np.isnan(null)).sum(axis=0))
+    return np.where(np.isnan(observed), np.nan, p_value)
+
+
+def mcnemar_test(
+    y_true: np.ndarray,
+    y_pred_a: np.ndarray,
+    y_pred_b: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    exact: Optional[bool] = None,
+) -> Dict[str, Result]:
+    """McNemar's test of the correctness of two models on the same values
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_pred_a (np.ndarray): array of pred values of model A (must be bool or int
+            types)
+        y_pred_b (np.ndarray): array of pred values of model B (must be bool or int
+            types)
+        labels (optional | list or np.ndarray):
+            labels to compare the models on (must be bool or int types)
+        exact (optional | bool): exact binomial test instead of the chi-squared
+            approximation, defaults to exact below 25 discordant values
+    Returns:
+        Dict[str, Result]: statistic, p_value and effect_size (accuracy of A minus
+            accuracy of B)
+    """
+    counts, _ = _paired_counts_from_ext(y_true, y_pred_a, y_pred_b, labels)
+
+    diag = np.arange(len(counts))
+    both = counts[diag, diag, diag].sum()
+    n01 = counts[diag, diag, :].sum() - both  # A correct and B wrong
+    n10 = counts[diag, :, diag].sum() - both  # A wrong and B correct
+    if exact is None:
+        exact = n01 + n10 < 25
+    statistic, p_value = _mcnemar(int(n01), int(n10), bool(exact))
+
+    n = counts.sum()
+    effect_size = (n01 - n10) / n if n else np.nan
+    return _result(np.array(statistic), np.array(p_value), np.array(effect_size))
+
+
+def paired_permutation_test(
+    y_true: np.ndarray,
+    y_pred_a: np.ndarray,
+    y_pred_b: np.ndarray,
+    metric: BootstrapMetric = BootstrapMetric.F1,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    average: AverageType = AverageType.MACRO,
+    pos_label: Optional[int] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    n_permutations: int = 1000,
+    seed: int = 0,
+) -> Dict[str, Result]:
+    """Paired permutation test of the metric difference of two models, the
+    predictions of every value are swapped between the models at random
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_pred_a (np.ndarray): array of pred values of model A (must be bool or int
+            types)
+        y_pred_b (np.ndarray): array of pred values of model B (must be bool or int
+            types)
+        metric (optional | str): "precision", "recall", "f1" or "iou"
+        labels (optional | list or np.ndarray):
+            labels to compare the models on (must be bool or int types)
+        average (optional | str): strategy for averaging across classes
+        pos_label (optional | int): only score this label (binary metric)
+        zero_division (optional | str): strategy to handle division by 0
+        n_permutations (optional | int): number of random permutations
+        seed (optional | int): seed of the random generator
+    Returns:
+        Dict[str, Result]: statistic (difference over the standard deviation of
+            the permuted differences), two sided p_value and effect_size (metric
+            of A minus metric of B), 1D arrays per class when average is "none"
+    """
+    metric = BootstrapMetric(metric)
+    average = AverageType(average)
+    zero_division = ZeroDivision(zero_division)
+    if n_permutations < 1:
+        raise ValueError("n_permutations must be >= 1")
+    if seed < 0:
+        raise ValueError("seed must be >= 0")
+    counts, labels = _paired_counts_from_ext(y_true, y_pred_a, y_pred_b, labels)
+    pos_index = _pos_index(labels, pos_label)
+
+    units, multiplicity = _units(counts)
+    total = (units * multiplicity[:, np.newaxis, np.newaxis, np.newaxis]).sum(axis=0)
+    observed = _difference(total, metric, average, zero_division, pos_index)
+    null = _difference(
+        _paired_permutation_reqs(counts, n_permutations, seed),
+        metric,
+        average,
+        zero_division,
+        pos_index,
+    )
+    with np.errstate(divide="ignore", invalid="ignore"):
+        statistic = observed / np.nanstd(null, axis=0)
+    return _result(statistic, _p_value(null, observed), observed)
+
+
+def paired_bootstrap_test(
+    y_true: np.ndarray,
+    y_pred_a: np.ndarray,
+    y_pred_b: np.ndarray,
+    metric: BootstrapMetric = BootstrapMetric.F1,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    average: AverageType = AverageType.MACRO,
+    pos_label: Optional[int] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    n_boot: int = 1000,
+    seed: int = 0,
+) -> Dict[str, Result]:
+    """Paired bootstrap test of the metric difference of two models, the values
+    are resampled with both predictions so the replicates keep the pairing
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_pred_a (np.ndarray): array of pred values of model A (must be bool or int
+            types)
+        y_pred_b (np.ndarray): array of pred values of model B (must be bool or int
+            types)
+        metric (optional | str): "precision", "recall", "f1" or "iou"
+        labels (optional | list or np.ndarray):
+            labels to compare the models on (must be bool or int types)
+        average (optional | str): strategy for averaging across classes
+        pos_label (optional | int): only score this label (binary metric)
+        zero_division (optional | str): strategy to handle division by 0
+        n_boot (optional | int): number of bootstrap replicates
+        seed (optional | int): seed of the random generator
+    Returns:
+        Dict[str, Result]: statistic (difference over its bootstrap standard
+            error), two sided p_value of the replicates shifted to no difference
+            and effect_size (metric of A minus metric of B), 1D arrays per class
+            when average is "none"
+    """
+    metric = BootstrapMetric(metric)
+    average = AverageType(average)
+    zero_division = ZeroDivision(zero_division)
+    if n_boot < 1:
+        raise ValueError("n_boot must be >= 1")
+    if seed < 0:
+        raise ValueError("seed must be >= 0")
+    counts, labels = _paired_counts_from_ext(y_true, y_pred_a, y_pred_b, labels)
+    pos_index = _pos_index(labels, pos_label)
+
+    units, multiplicity = _units(counts)
+    flat = np.ascontiguousarray(units.reshape(len(units), -1), dtype=np.int64)
+    totals = _bootstrap_totals(flat, multiplicity, n_boot, seed)
+    total = (flat * multiplicity[:, np.newaxis]).sum(axis=0)
+    observed = _difference(
+        total.reshape(units.shape[1:]), metric, average, zero_division, pos_index
+    )
+    replicates = _difference(
+        totals.reshape((n_boot,) + units.shape[1:]),
+        metric,
+        average,
+        zero_division,
+        pos_index,
+    )
+    with np.errstate(divide="ignore", invalid="ignore"):
+        statistic = observed / np.nanstd(replicates, axis=0)
+    return _result(statistic, _p_value(replicates - observed, observed), observed)
@@ -1,25 +1,73 @@
-from typing import List, Optional, Union
+from typing import List, Optional, Tuple, Union
 
 import numpy as np
 
-from ._fast_stats_ext import _confusion_matrix, _unique
+from ._fast_stats_ext import (
+    _confusion_matrix,
+    _confusion_matrix_grouped,
+    _confusion_matrix_scores,
+    _unique,
+)
 from .exceptions import ShapeError
 
 
+def _group_indices(
+    groups: np.ndarray, shape: Tuple[int, ...]
+) -> Tuple[np.ndarray, np.ndarray]:
+    # sorted group ids and the index into them of every leading entry of y_true
+    if not isinstance(groups, np.ndarray):
+        raise TypeError("groups must be a numpy array")
+    if groups.ndim == 0 or groups.shape != shape[: groups.ndim]:
+        raise ShapeError("groups must match the leading axes of y_true")
+    ids, inverse = np.unique(groups, return_inverse=True)
+    return ids, np.ascontiguousarray(inverse.ravel(), dtype=np.int64)
+
+
+def _confusion_matrix_from_scores(
+    y_true: np.ndarray,
+    scores: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]],
+    class_axis: int,
+) -> Tuple[np.ndarray, np.ndarray]:
+    if not -scores.ndim <= class_axis < scores.ndim:
+        raise ValueError("class_axis out of range of y_pred dimensions")
+    class_axis = class_axis % scores.ndim
+    if np.delete(scores.shape, class_axis).tolist() != list(y_true.shape):
+        raise ShapeError("y_pred must be y_true shape with an extra class axis")
+
+    if labels is None:
+        labels = np.arange(scores.shape[class_axis], dtype=y_true.dtype)
+    else:
+        labels = np.array(labels, dtype=y_true.dtype)
+    if len(labels) != scores.shape[class_axis]:
+        raise ValueError("labels must have one entry per class_axis channel")
+
+    return _confusion_matrix_scores(y_true, labels, scores, class_axis), labels
+
+
 def confusion_matrix(
     y_true: np.ndarray,
     y_pred: np.ndarray,
     labels: Optional[Union[List, np.ndarray]] = None,
+    class_axis: Optional[int] = None,
+    groups: Optional[np.ndarray] = None,
 ) -> np.ndarray:
     """Calculation of confusion matrix
 
     Args:
         y_true (np.ndarray): array of true values (must be bool or int types)
-        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types) or
+            array of scores (must be float types) when class_axis is given
         labels (optional | list or np.ndarray):
             labels to calculate confusion matrix for (must be bool or int types)
+        class_axis (optional | int): axis of y_pred scores to take the argmax over,
+            labels then default to the class channel indices
+        groups (optional | np.ndarray): group id (e.g. site or patient) of every
+            entry of the leading axes of y_true
     Returns:
-        confusion matrix (np.ndarray): 2D np.ndarray confusion matrix
+        confusion matrix (np.ndarray): 2D np.ndarray confusion matrix, or a 3D
+            (n_groups, n_labels, n_labels) stack ordered by sorted group id when
+            groups is given
     """
     if not all(
         [
@@ -28,6 +76,10 @@ def confusion_matrix(
         ]
     ):
         raise TypeError("y_true and y_pred must be numpy arrays")
+    if class_axis is not None:
+        if groups is not None:
+            raise ValueError("groups is not supported with class_axis")
+        return _confusion_matrix_from_scores(y_true, y_pred, labels, class_axis)[0]
     if y_true.shape != y_pred.shape:
         raise ShapeError("y_true and y_pred must be same shape")
 
@@ -38,4 +90,7 @@ def confusion_matrix(
     elif isinstance(labels, list):
         labels = np.array(labels, dtype=y_true.dtype)
 
+    if groups is not None:
+        ids, inverse = _group_indices(groups, y_true.shape)
+        return _confusion_matrix_grouped(y_true, y_pred, labels, inverse, len(ids))
     return _confusion_matrix(y_true, y_pred, labels)
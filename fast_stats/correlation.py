@@ -0,0 +1,78 @@
+from typing import Union
+
+import numpy as np
+
+from ._fast_stats_ext import _correlation
+from .exceptions import ShapeError
+
+Correlation = Union[float, np.ndarray]
+
+
+def _correlate(x: np.ndarray, y: np.ndarray, method: str) -> Correlation:
+    if not all(
+        [
+            isinstance(x, np.ndarray),
+            isinstance(y, np.ndarray),
+        ]
+    ):
+        raise TypeError("x and y must be numpy arrays")
+    if x.shape != y.shape:
+        raise ShapeError("x and y must be same shape")
+    if x.ndim not in (1, 2):
+        raise ShapeError("x and y must be (N,) or (N, D) arrays")
+    if x.shape[0] < 2:
+        raise ValueError("x and y must have at least 2 samples")
+
+    # keep float32 arrays as they are, everything else is computed in float64
+    dtype = np.result_type(x, y, np.float32)
+    if dtype != np.float32:
+        dtype = np.float64
+    res = _correlation(
+        np.ascontiguousarray(x.reshape(x.shape[0], -1), dtype=dtype),
+        np.ascontiguousarray(y.reshape(y.shape[0], -1), dtype=dtype),
+        method,
+    )
+    if x.ndim == 1:
+        return res.item()
+    return res
+
+
+def pearsonr(x: np.ndarray, y: np.ndarray) -> Correlation:
+    """Calculation of the Pearson correlation coefficient
+
+    Args:
+        x (np.ndarray): (N,) or (N, D) array of values
+        y (np.ndarray): (N,) or (N, D) array of values
+    Returns:
+        Correlation: float, or 1D array with the correlation of every column.
+            NaN when the values contain NaN or are constant
+    """
+    return _correlate(x, y, "pearson")
+
+
+def spearmanr(x: np.ndarray, y: np.ndarray) -> Correlation:
+    """Calculation of the Spearman rank correlation coefficient, tied values get
+    the average of their ranks
+
+    Args:
+        x (np.ndarray): (N,) or (N, D) array of values
+        y (np.ndarray): (N,) or (N, D) array of values
+    Returns:
+        Correlation: float, or 1D array with the correlation of every column.
+            NaN when the values contain NaN or are constant
+    """
+    return _correlate(x, y, "spearman")
+
+
+def kendall_tau(x: np.ndarray, y: np.ndarray) -> Correlation:
+    """Calculation of Kendall's tau-b, which accounts for ties, in O(N log N)
+    using Knight's algorithm
+
+    Args:
+        x (np.ndarray): (N,) or (N, D) array of values
+        y (np.ndarray): (N,) or (N, D) array of values
+    Returns:
+        Correlation: float, or 1D array with the correlation of every column.
+            NaN when the values contain NaN or are constant
+    """
+    return _correlate(x, y, "kendall")
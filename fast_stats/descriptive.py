@@ -0,0 +1,127 @@
+from typing import Dict, Optional, Union
+
+import numpy as np
+
+from ._fast_stats_ext import _merge_moments, _moments
+from .exceptions import ShapeError
+
+Stat = Union[float, np.ndarray]
+
+
+def _as_float(arr: np.ndarray) -> np.ndarray:
+    if not isinstance(arr, np.ndarray):
+        raise TypeError("arr must be a numpy array")
+    # keep float32 arrays as they are, everything else is computed in float64
+    if arr.dtype == np.float32:
+        return arr
+    return arr.astype(np.float64, copy=False)
+
+
+def _normalize_axis(axis: Optional[int], ndim: int) -> Optional[int]:
+    if axis is None:
+        return None
+    if not -ndim <= axis < ndim:
+        raise ValueError(f"axis {axis} is out of bounds for array of dimension {ndim}")
+    return axis % ndim
+
+
+def _item(x: np.ndarray) -> Stat:
+    return x.item() if x.ndim == 0 else x
+
+
+class DescriptiveStats:
+    """Streaming accumulator of count, mean, variance, std, min, max, skewness and
+    kurtosis. Arrays are added with `update` and accumulators filled from separate
+    chunks or workers are combined exactly with `merge`.
+
+    Args:
+        axis (optional | int): axis reduced by every update, all axes when None.
+            Arrays added to the same accumulator must agree on the other axes
+    """
+
+    def __init__(self, axis: Optional[int] = None):
+        self.axis = axis
+        self.state: Optional[np.ndarray] = None
+
+    def _add_state(self, state: np.ndarray):
+        if self.state is None:
+            self.state = state
+            return
+        if self.state.shape != state.shape:
+            raise ShapeError("arrays must have the same shape apart from axis")
+        self.state = _merge_moments(self.state, state)
+
+    def update(self, arr: np.ndarray) -> "DescriptiveStats":
+        """Add the values of an array
+
+        Args:
+            arr (np.ndarray): array of values
+        Returns:
+            DescriptiveStats: the accumulator itself
+        """
+        arr = _as_float(arr)
+        self._add_state(_moments(arr, _normalize_axis(self.axis, arr.ndim)))
+        return self
+
+    def merge(self, other: "DescriptiveStats") -> "DescriptiveStats":
+        """Add the values accumulated by another accumulator
+
+        Args:
+            other (DescriptiveStats): accumulator reducing the same axis
+        Returns:
+            DescriptiveStats: the accumulator itself
+        """
+        if self.axis != other.axis:
+            raise ValueError("accumulators must reduce the same axis")
+        if other.state is not None:
+            self._add_state(other.state)
+        return self
+
+    def result(self, ddof: int = 0) -> Dict[str, Stat]:
+        """Statistics of the values added so far, skewness and kurtosis are the
+        biased estimates and kurtosis is the excess (Fisher) kurtosis
+
+        Args:
+            ddof (optional | int): delta degrees of freedom of the variance
+        Returns:
+            Dict[str, Stat]: stats as floats, or arrays when an axis is reduced
+        """
+        state = self.state
+        if state is None:
+            if self.axis is not None:
+                raise ValueError("no arrays have been added")
+            state = _moments(np.empty(0), None)
+
+        n, mean, m2, m3, m4, min_, max_ = np.moveaxis(state, -1, 0)
+        empty = n == 0
+        with np.errstate(divide="ignore", invalid="ignore"):
+            variance = np.where(n > ddof, m2 / (n - ddof), np.nan)
+            skewness = np.sqrt(n) * m3 / m2**1.5
+            kurtosis = n * m4 / m2**2 - 3
+
+        return {
+            "count": _item(n.astype(np.int64)),
+            "mean": _item(np.where(empty, np.nan, mean)),
+            "variance": _item(variance),
+            "std": _item(np.sqrt(variance)),
+            "min": _item(np.where(empty, np.nan, min_)),
+            "max": _item(np.where(empty, np.nan, max_)),
+            "skewness": _item(skewness),
+            "kurtosis": _item(kurtosis),
+        }
+
+
+def describe(
+    arr: np.ndarray, axis: Optional[int] = None, ddof: int = 0
+) -> Dict[str, Stat]:
+    """Calculation of count, mean, variance, std, min, max, skewness and kurtosis
+    in a single numerically stable pass
+
+    Args:
+        arr (np.ndarray): array of values
+        axis (optional | int): axis to reduce, all axes when None
+        ddof (optional | int): delta degrees of freedom of the variance
+    Returns:
+        Dict[str, Stat]: stats as floats, or arrays when an axis is reduced
+    """
+    return DescriptiveStats(axis).update(arr).result(ddof)
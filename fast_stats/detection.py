@@ -0,0 +1,119 @@
+from typing import Dict, List, Optional, Sequence
+
+import numpy as np
+
+from ._fast_stats_ext import _detection_eval
+from .boxes import BoxFormat, _to_xyxy
+
+# area ranges of COCOeval in pixels (all, small, medium, large)
+AREA_RANGES = [(0.0, 1e10), (0.0, 32.0**2), (32.0**2, 96.0**2), (96.0**2, 1e10)]
+
+
+def _concat_boxes(
+    samples: List[Dict[str, np.ndarray]], box_format: BoxFormat
+) -> Dict[str, np.ndarray]:
+    # start from empty arrays so no images still concatenate
+    boxes, keys = [np.zeros((0, 4))], [np.zeros((0, 2), dtype=np.int64)]
+    scores, areas, crowd = [], [np.zeros(0)], [np.zeros(0, dtype=bool)]
+    for image, sample in enumerate(samples):
+        xyxy = _to_xyxy(
+            np.asarray(sample["boxes"], dtype=np.float64).reshape(-1, 4), box_format
+        )
+        labels = np.asarray(sample["labels"], dtype=np.int64).reshape(-1)
+        if len(labels) != len(xyxy):
+            raise ValueError("boxes and labels must have the same length")
+        boxes.append(xyxy)
+        keys.append(np.stack([np.full(len(labels), image), labels], axis=1))
+        if "scores" in sample:
+            scores.append(np.asarray(sample["scores"], dtype=np.float64).reshape(-1))
+        box_areas = (xyxy[:, 2] - xyxy[:, 0]) * (xyxy[:, 3] - xyxy[:, 1])
+        areas.append(np.asarray(sample.get("area", box_areas), dtype=np.float64))
+        crowd.append(
+            np.asarray(sample.get("iscrowd", np.zeros(len(labels))), dtype=bool)
+        )
+
+    return {
+        "boxes": np.concatenate(boxes),
+        "keys": np.concatenate(keys).astype(np.int64),
+        "scores": np.concatenate(scores) if scores else np.zeros(0),
+        "areas": np.concatenate(areas),
+        "crowd": np.concatenate(crowd),
+    }
+
+
+def detection_map(
+    preds: List[Dict[str, np.ndarray]],
+    target: List[Dict[str, np.ndarray]],
+    box_format: BoxFormat = BoxFormat.XYXY,
+    iou_thresholds: Optional[Sequence[float]] = None,
+    max_detections: Sequence[int] = (1, 10, 100),
+) -> Dict[str, float]:
+    """COCO-style mean average precision and recall for object detection, returns
+    the 12 numbers of the COCOeval summary
+
+    Args:
+        preds (list of dicts): per image "boxes" (N, 4), "scores" (N,) and
+            "labels" (N,) of the detections
+        target (list of dicts): per image "boxes" (N, 4) and "labels" (N,) of the
+            ground truth, with optional "area" (N,) and "iscrowd" (N,)
+        box_format (optional | str): box coordinates as "xyxy", "xywh" or "cxcywh"
+        iou_thresholds (optional | sequence of floats): defaults to 0.5:0.05:0.95
+        max_detections (optional | sequence of ints): three detection limits per
+            image, the largest is used for AP
+    Returns:
+        Dict[str, float]: map, map_50, map_75, map_small, map_medium, map_large,
+            mar_<limit> for every limit, mar_small, mar_medium and mar_large,
+            -1 where there is no ground truth (like COCOeval)
+    """
+    if len(preds) != len(target):
+        raise ValueError("preds and target must have one entry per image")
+    if len(max_detections) != 3:
+        raise ValueError("max_detections must have three entries")
+    box_format = BoxFormat(box_format)
+    if iou_thresholds is None:
+        iou_thresholds = np.linspace(0.5, 0.95, 10)
+    iou_thresholds = np.asarray(iou_thresholds, dtype=np.float64)
+    max_detections = sorted(int(m) for m in max_detections)
+
+    gt, dt = _concat_boxes(target, box_format), _concat_boxes(preds, box_format)
+    if len(dt["scores"]) != len(dt["boxes"]):
+        raise ValueError("preds must have one score per box")
+    precision, recall = _detection_eval(
+        gt["boxes"],
+        gt["keys"],
+        gt["areas"],
+        gt["crowd"],
+        dt["boxes"],
+        dt["keys"],
+        dt["scores"],
+        iou_thresholds.tolist(),
+        AREA_RANGES,
+        max_detections,
+    )
+
+    def summarize(ap: bool, iou_threshold=None, area: int = 0, max_det: int = 2):
+        t = np.ones(len(iou_thresholds), dtype=bool)
+        if iou_threshold is not None:
+            t = np.isclose(iou_thresholds, iou_threshold)
+        s = precision[t, :, :, area, max_det] if ap else recall[t, :, area, max_det]
+        s = s[s > -1]
+        return s.mean().item() if s.size else -1.0
+
+    stats = {
+        "map": summarize(True),
+        "map_50": summarize(True, iou_threshold=0.5),
+        "map_75": summarize(True, iou_threshold=0.75),
+        "map_small": summarize(True, area=1),
+        "map_medium": summarize(True, area=2),
+        "map_large": summarize(True, area=3),
+    }
+    for m, limit in enumerate(max_detections):
+        stats.update({f"mar_{limit}": summarize(False, max_det=m)})
+    stats.update(
+        {
+            "mar_small": summarize(False, area=1),
+            "mar_medium": summarize(False, area=2),
+            "mar_large": summarize(False, area=3),
+        }
+    )
+    return stats
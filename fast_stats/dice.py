@@ -0,0 +1,134 @@
+from enum import Enum
+from typing import List, Optional, Union
+
+import numpy as np
+
+from ._fast_stats_ext import (
+    _binary_f1_score_reqs,
+    _binary_f1_score_reqs_batch,
+    _confusion_matrix,
+    _confusion_matrix_batch,
+    _unique,
+)
+from .exceptions import ShapeError
+from .multiclass import Result, ZeroDivision, _get_zero_handler
+
+
+class WeightType(Enum):
+    SQUARE = "square"
+    SIMPLE = "simple"
+    UNIFORM = "uniform"
+
+
+def _volume_weights(volume: np.ndarray, weight_type: WeightType) -> np.ndarray:
+    with np.errstate(divide="ignore"):
+        if weight_type == WeightType.SQUARE:
+            w = 1.0 / volume.astype(np.float64) ** 2
+        elif weight_type == WeightType.SIMPLE:
+            w = 1.0 / volume.astype(np.float64)
+        else:
+            w = np.ones(volume.shape)
+    # classes absent from y_true get the largest weight of the sample
+    infs = np.isinf(w)
+    w[infs] = 0.0
+    return w + infs * w.max(axis=-1, keepdims=True)
+
+
+def dice_score(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    per_sample: bool = False,
+) -> Result:
+    """Calculation of the Dice coefficient for binary masks
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        zero_division (optional | str): strategy to handle division by 0
+        per_sample (optional | bool): score every entry of the leading batch axis
+    Returns:
+        Result: None or float, or 1D array of scores per sample
+    """
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    zero_division = ZeroDivision(zero_division)
+
+    if per_sample:
+        x = _binary_f1_score_reqs_batch(y_true, y_pred)
+        zero_handle = _get_zero_handler(zero_division)
+        with np.errstate(divide="ignore", invalid="ignore"):
+            return zero_handle(2 * x[:, 0] / (x[:, 1] + x[:, 2]))
+
+    tp, tp_fp, tp_fn = _binary_f1_score_reqs(y_true, y_pred)
+    if tp_fp + tp_fn == 0:
+        if zero_division == ZeroDivision.NONE:
+            return None
+        elif zero_division == ZeroDivision.ZERO:
+            return 0.0
+    return 2 * tp / (tp_fp + tp_fn)
+
+
+def generalized_dice(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    weight_type: WeightType = WeightType.SQUARE,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    per_sample: bool = False,
+) -> Result:
+    """Multi-class calculation of the generalized Dice coefficient, classes are
+    weighted by their inverse (squared) volume in y_true
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        labels (optional | list or np.ndarray):
+            labels to calculate the score for (must be bool or int types)
+        weight_type (optional | str): class weighting of "square", "simple"
+            (inverse volume) or "uniform"
+        zero_division (optional | str): strategy to handle division by 0
+        per_sample (optional | bool): score every entry of the leading batch axis
+    Returns:
+        Result: scalar value, or 1D array of scores per sample
+    """
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    weight_type = WeightType(weight_type)
+    zero_division = ZeroDivision(zero_division)
+
+    if labels is None:
+        labels = np.array(
+            sorted(list(_unique(y_true).union(_unique(y_pred)))), dtype=y_true.dtype
+        )
+    elif isinstance(labels, list):
+        labels = np.array(labels, dtype=y_true.dtype)
+
+    if per_sample:
+        cm = _confusion_matrix_batch(y_true, y_pred, labels)
+    else:
+        cm = _confusion_matrix(y_true, y_pred, labels)[np.newaxis]
+    tp = np.diagonal(cm, axis1=1, axis2=2)
+    tp_fp, tp_fn = cm.sum(axis=1), cm.sum(axis=2)
+
+    w = _volume_weights(tp_fn, weight_type)
+    zero_handle = _get_zero_handler(zero_division)
+    with np.errstate(divide="ignore", invalid="ignore"):
+        gd = zero_handle(2 * (w * tp).sum(axis=1) / (w * (tp_fp + tp_fn)).sum(axis=1))
+    if per_sample:
+        return gd
+    return gd[0].item()
@@ -0,0 +1,95 @@
+from typing import Dict, Optional, Tuple, Union
+
+import numpy as np
+
+from ._fast_stats_ext import _instance_reqs, _label_components
+from .binary import Result, ZeroDivision, _precision, _recall
+from .exceptions import ShapeError
+
+
+def _full_connectivity(ndim: int, connectivity: Optional[int]) -> bool:
+    if connectivity is None or connectivity == 2 * ndim:
+        return False
+    if connectivity == 3**ndim - 1:
+        return True
+    raise ValueError(
+        f"connectivity must be {2 * ndim} or {3**ndim - 1} for {ndim}-D arrays"
+    )
+
+
+def label_components(
+    mask: np.ndarray,
+    connectivity: Optional[int] = None,
+) -> Tuple[np.ndarray, int]:
+    """Labelling of the connected components of a binary mask
+
+    Args:
+        mask (np.ndarray): array of 0/1 values (must be bool or int types)
+        connectivity (optional | int): number of neighbours of an element, 4 or 8
+            in 2-D and 6 or 26 in 3-D (defaults to face neighbours)
+    Returns:
+        Tuple[np.ndarray, int]: component labels 1..n (0 is background) and n
+    """
+    if not isinstance(mask, np.ndarray):
+        raise TypeError("mask must be a numpy array")
+    return _label_components(mask, _full_connectivity(mask.ndim, connectivity))
+
+
+def instance_stats(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    iou_threshold: float = 0.5,
+    connectivity: Optional[int] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+) -> Dict[str, Union[int, Result]]:
+    """Object-level TP, FP, FN, precision, recall and f1-score of binary masks,
+    the connected components of y_true and y_pred are matched one-to-one in order
+    of descending IoU
+
+    Args:
+        y_true (np.ndarray): array of 0/1 values (must be bool or int types)
+        y_pred (np.ndarray): array of 0/1 values (must be bool or int types)
+        iou_threshold (optional | float): minimum IoU of a matched pair
+        connectivity (optional | int): number of neighbours of an element, 4 or 8
+            in 2-D and 6 or 26 in 3-D (defaults to face neighbours)
+        zero_division (optional | str): strategy to handle division by 0
+    Returns:
+        Dict[str, Union[int, Result]]: object counts and stats
+    """
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    if not 0 <= iou_threshold <= 1:
+        raise ValueError("iou_threshold must be in [0, 1]")
+    zero_division = ZeroDivision(zero_division)
+
+    full = _full_connectivity(y_true.ndim, connectivity)
+    tp, n_true, n_pred = _instance_reqs(y_true, y_pred, float(iou_threshold), full)
+    p, r = _precision(tp, n_pred, zero_division), _recall(tp, n_true, zero_division)
+    stats = dict({"tp": tp, "fp": n_pred - tp, "fn": n_true - tp})
+    stats.update({"precision": p, "recall": r})
+
+    # convert p and/or r to 0 if None
+    if p is None:
+        p = 0.0
+    if r is None:
+        r = 0.0
+
+    # handle 0 cases
+    if p + r == 0:
+        if zero_division == ZeroDivision.NONE:
+            f1 = None
+        elif zero_division == ZeroDivision.ZERO:
+            f1 = 0.0
+    else:
+        f1 = 2 * p * r / (p + r)
+
+    stats.update({"f1-score": f1})
+
+    return stats
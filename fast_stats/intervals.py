@@ -0,0 +1,59 @@
+from enum import Enum
+from typing import Tuple, Union
+
+import numpy as np
+
+from ._fast_stats_ext import _proportion_interval
+from .exceptions import ShapeError
+
+
+class IntervalType(Enum):
+    WILSON = "wilson"
+    CLOPPER_PEARSON = "clopper_pearson"
+    AGRESTI_COULL = "agresti_coull"
+
+
+Interval = Union[Tuple[float, float], np.ndarray]
+
+
+def _interval(
+    successes: np.ndarray,
+    trials: np.ndarray,
+    method: IntervalType,
+    confidence: float,
+) -> np.ndarray:
+    successes = np.ascontiguousarray(successes, dtype=np.float64)
+    trials = np.ascontiguousarray(trials, dtype=np.float64)
+    res = _proportion_interval(
+        successes.ravel(), trials.ravel(), confidence, method.value
+    )
+    return res.reshape(successes.shape + (2,))
+
+
+def proportion_ci(
+    successes: Union[int, np.ndarray],
+    trials: Union[int, np.ndarray],
+    method: IntervalType = IntervalType.WILSON,
+    confidence: float = 0.95,
+) -> Interval:
+    """Analytical confidence interval of a proportion such as precision, recall or
+    accuracy from its counts
+
+    Args:
+        successes (int or np.ndarray): number of successes, e.g. TP for precision
+        trials (int or np.ndarray): number of trials, e.g. TP + FP for precision
+        method (optional | str): "wilson", "clopper_pearson" (exact) or
+            "agresti_coull"
+        confidence (optional | float): confidence level of the interval
+    Returns:
+        Interval: (low, high) for scalar counts, otherwise array with a trailing
+            axis of low and high, NaN where there are no trials
+    """
+    method = IntervalType(method)
+    if np.shape(successes) != np.shape(trials):
+        raise ShapeError("successes and trials must be same shape")
+
+    res = _interval(np.asarray(successes), np.asarray(trials), method, confidence)
+    if res.ndim == 1:
+        return res[0].item(), res[1].item()
+    return res
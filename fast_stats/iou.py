@@ -1,17 +1,10 @@
-from enum import Enum
-from typing import Union
+from typing import List, Optional, Union
 
 import numpy as np
 
-from ._fast_stats_ext import _binary_f1_score_reqs
+from ._fast_stats_ext import _binary_f1_score_reqs, _confusion_matrix, _unique
 from .exceptions import ShapeError
-
-Result = Union[None, float]
-
-
-class ZeroDivision(Enum):
-    ZERO = "zero"
-    NONE = "none"
+from .multiclass import AverageType, Result, ZeroDivision, _get_zero_handler
 
 
 def _iou(
@@ -53,3 +46,101 @@ def iou(
     tp, tp_fp, tp_fn = _binary_f1_score_reqs(array1, array2)
     fp, fn = tp_fp - tp, tp_fn - tp
     return _iou(tp, fp, fn, zero_division)
+
+
+def _multiclass_iou_reqs(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]],
+    ignore_index: Optional[int],
+) -> np.ndarray:
+    present = _unique(y_true).union(_unique(y_pred))
+    if labels is None:
+        labels = np.array(
+            sorted(list(present.difference({ignore_index}))), dtype=y_true.dtype
+        )
+    else:
+        labels = np.array(labels, dtype=y_true.dtype)
+
+    # the confusion matrix covers every present value so predictions outside of
+    # labels still count as FN and FP, only the y_true == ignore_index row is dropped
+    all_labels = present.union(labels.tolist())
+    if ignore_index is not None:
+        all_labels.add(ignore_index)
+    all_labels = np.array(sorted(list(all_labels)), dtype=y_true.dtype)
+    cm = _confusion_matrix(y_true, y_pred, all_labels)
+    if ignore_index is not None:
+        cm[all_labels == ignore_index, :] = 0
+
+    idx = np.searchsorted(all_labels, labels)
+    tp, tp_fp, tp_fn = np.diag(cm)[idx], cm.sum(axis=0)[idx], cm.sum(axis=1)[idx]
+    return np.stack([tp, tp_fp - tp, tp_fn - tp], axis=1)
+
+
+def jaccard_score(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    ignore_index: Optional[int] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    average: AverageType = AverageType.NONE,
+) -> Result:
+    """Multi-class calculation of IoU (Jaccard index) for label maps.
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        labels (optional | list or np.ndarray):
+            labels to calculate IoU for (must be bool or int types)
+        ignore_index (optional | int): y_true value excluded from the calculation
+        zero_division (optional | str): strategy to handle division by 0
+        average (optional | str): strategy for averaging across classes
+    Returns:
+        IoU (np.ndarray): 1D array or scalar values depending on averaging
+    """
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    zero_division = ZeroDivision(zero_division)
+    average = AverageType(average)
+
+    x = _multiclass_iou_reqs(y_true, y_pred, labels, ignore_index)
+    zero_handle = _get_zero_handler(zero_division)
+    with np.errstate(divide="ignore", invalid="ignore"):
+        if average == AverageType.NONE:
+            return zero_handle(x[:, 0] / x.sum(axis=1))
+        elif average == AverageType.MICRO:
+            return zero_handle(x[:, 0].sum() / x.sum()).item()
+        elif average == AverageType.MACRO:
+            return np.nanmean(zero_handle(x[:, 0] / x.sum(axis=1))).item()
+        return None  # pragma: no cover
+
+
+def mean_iou(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    ignore_index: Optional[int] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+) -> Result:
+    """Calculation of mean IoU (mIoU) over the classes of label maps.
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        labels (optional | list or np.ndarray):
+            labels to calculate IoU for (must be bool or int types)
+        ignore_index (optional | int): y_true value excluded from the calculation
+        zero_division (optional | str): strategy to handle division by 0
+    Returns:
+        Result: mean of the per-class IoU
+    """
+    return jaccard_score(
+        y_true, y_pred, labels, ignore_index, zero_division, AverageType.MACRO
+    )
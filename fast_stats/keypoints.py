@@ -0,0 +1,150 @@
+from typing import Optional, Tuple
+
+import numpy as np
+
+from ._fast_stats_ext import _oks_reqs, _pck_reqs
+from .exceptions import ShapeError
+from .multiclass import Result, ZeroDivision, _get_zero_handler
+
+# per-keypoint sigmas of the 17 COCO person keypoints
+COCO_SIGMAS = (
+    np.array(
+        [
+            0.26,
+            0.25,
+            0.25,
+            0.35,
+            0.35,
+            0.79,
+            0.79,
+            0.72,
+            0.72,
+            0.62,
+            0.62,
+            1.07,
+            1.07,
+            0.87,
+            0.87,
+            0.89,
+            0.89,
+        ]
+    )
+    / 10.0
+)
+
+
+def _as_keypoints(
+    y_true: np.ndarray, y_pred: np.ndarray
+) -> Tuple[np.ndarray, np.ndarray]:
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.ndim != 3 or y_true.shape[2] not in (2, 3):
+        raise ShapeError("y_true must have shape (N, K, 2) or (N, K, 3)")
+    if y_pred.shape[:2] != y_true.shape[:2] or y_pred.shape[2] not in (2, 3):
+        raise ShapeError("y_pred must have shape (N, K, 2) or (N, K, 3)")
+    return (
+        np.ascontiguousarray(y_true, dtype=np.float64),
+        np.ascontiguousarray(y_pred, dtype=np.float64),
+    )
+
+
+def keypoint_oks(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    areas: np.ndarray,
+    sigmas: Optional[np.ndarray] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    per_sample: bool = False,
+) -> Result:
+    """Calculation of the object keypoint similarity (OKS) as in COCO, averaged
+    over the keypoints visible in y_true
+
+    Args:
+        y_true (np.ndarray): (N, K, 2) or (N, K, 3) array of true keypoints where
+            the third column is the visibility (0 is not labelled)
+        y_pred (np.ndarray): (N, K, 2) or (N, K, 3) array of pred keypoints
+        areas (np.ndarray): (N,) array of object areas (the squared scale)
+        sigmas (optional | np.ndarray): (K,) array of per-keypoint falloffs,
+            defaults to the COCO person sigmas for K = 17
+        zero_division (optional | str): strategy to handle division by 0
+        per_sample (optional | bool): return the OKS of every sample
+    Returns:
+        Result: mean OKS over the samples, or 1D array of OKS per sample
+    """
+    y_true, y_pred = _as_keypoints(y_true, y_pred)
+    if sigmas is None:
+        if y_true.shape[1] != len(COCO_SIGMAS):
+            raise ValueError("sigmas are required unless there are 17 keypoints")
+        sigmas = COCO_SIGMAS
+    areas = np.ascontiguousarray(areas, dtype=np.float64)
+    sigmas = np.ascontiguousarray(sigmas, dtype=np.float64)
+    if areas.shape != (y_true.shape[0],):
+        raise ShapeError("areas must have shape (N,)")
+    if sigmas.shape != (y_true.shape[1],):
+        raise ShapeError("sigmas must have shape (K,)")
+    zero_division = ZeroDivision(zero_division)
+
+    x = _oks_reqs(y_true, y_pred, areas, sigmas)
+    zero_handle = _get_zero_handler(zero_division)
+    with np.errstate(divide="ignore", invalid="ignore"):
+        oks = zero_handle(x[:, 0] / x[:, 1])
+    if per_sample:
+        return oks
+    with np.errstate(divide="ignore", invalid="ignore"):
+        return zero_handle(np.nanmean(oks) if oks.size else np.nan).item()
+
+
+def keypoint_pck(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    alpha: float = 0.2,
+    normalize: Optional[np.ndarray] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    per_sample: bool = False,
+) -> Result:
+    """Calculation of the percentage of correct keypoints (PCK@alpha), a visible
+    keypoint is correct within alpha * normalize of its true position
+
+    Args:
+        y_true (np.ndarray): (N, K, 2) or (N, K, 3) array of true keypoints where
+            the third column is the visibility (0 is not labelled)
+        y_pred (np.ndarray): (N, K, 2) or (N, K, 3) array of pred keypoints
+        alpha (optional | float): fraction of the reference length
+        normalize (optional | np.ndarray): (N,) array of reference lengths (e.g.
+            head or torso size), defaults to the longest side of the box around
+            the visible true keypoints
+        zero_division (optional | str): strategy to handle division by 0
+        per_sample (optional | bool): return the PCK of every sample
+    Returns:
+        Result: PCK over all visible keypoints, or 1D array of PCK per sample
+    """
+    y_true, y_pred = _as_keypoints(y_true, y_pred)
+    if alpha < 0:
+        raise ValueError("alpha must be >= 0")
+    if normalize is None:
+        visible = np.ones(y_true.shape[:2], dtype=bool)
+        if y_true.shape[2] == 3:
+            visible = y_true[:, :, 2] > 0
+        xy = y_true[:, :, :2]
+        mask = visible[:, :, np.newaxis] & ~np.isnan(xy)
+        high = np.max(xy, axis=1, where=mask, initial=-np.inf)
+        low = np.min(xy, axis=1, where=mask, initial=np.inf)
+        # samples without visible keypoints get a reference length of 0
+        extent = (high - low).max(axis=1)
+        normalize = np.where(np.isfinite(extent), extent, 0.0)
+    normalize = np.ascontiguousarray(normalize, dtype=np.float64)
+    if normalize.shape != (y_true.shape[0],):
+        raise ShapeError("normalize must have shape (N,)")
+    zero_division = ZeroDivision(zero_division)
+
+    x = _pck_reqs(y_true, y_pred, alpha * normalize)
+    zero_handle = _get_zero_handler(zero_division)
+    with np.errstate(divide="ignore", invalid="ignore"):
+        if per_sample:
+            return zero_handle(x[:, 0] / x[:, 1])
+        return zero_handle(x[:, 0].sum() / x[:, 1].sum()).item()
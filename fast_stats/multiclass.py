@@ -1,12 +1,21 @@
 from enum import Enum
-from typing import Callable, Dict, List, Optional, Union
+from typing import Any, Callable, Dict, List, Optional, Tuple, Union
 
 import numpy as np
 
-from ._fast_stats_ext import _f1_score, _precision, _recall, _unique
+from ._fast_stats_ext import (
+    _confusion_matrix_grouped,
+    _f1_score,
+    _precision,
+    _recall,
+    _unique,
+)
+from .confusion_matrix import _confusion_matrix_from_scores, _group_indices
 from .exceptions import ShapeError
+from .intervals import Interval, IntervalType, _interval
 
 Result = Union[None, float, np.floating, np.ndarray]
+Stats = Dict[str, Union[Result, Interval]]
 
 
 class ZeroDivision(Enum):
@@ -36,22 +45,37 @@ def _get_zero_handler(
     return zero_handle
 
 
+def _reqs_from_scores(
+    y_true: np.ndarray,
+    scores: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]],
+    class_axis: int,
+) -> Tuple[np.ndarray, np.ndarray]:
+    cm, labels = _confusion_matrix_from_scores(y_true, scores, labels, class_axis)
+    # same TP, TP + FP and TP + FN columns as `_f1_score`
+    return labels, np.stack([np.diag(cm), cm.sum(axis=0), cm.sum(axis=1)], axis=1)
+
+
 def precision(
     y_true: np.ndarray,
     y_pred: np.ndarray,
     labels: Optional[Union[List, np.ndarray]] = None,
     zero_division: ZeroDivision = ZeroDivision.NONE,
     average: AverageType = AverageType.NONE,
+    class_axis: Optional[int] = None,
 ) -> Result:
     """Multi-class calculation of precision
 
     Args:
         y_true (np.ndarray): array of true values (must be bool or int types)
-        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types) or
+            array of scores (must be float types) when class_axis is given
         labels (optional | list or np.ndarray):
             labels to calculate confusion matrix for (must be bool or int types)
         zero_division (optional | str): strategy to handle division by 0
         average (optional | str): strategy for averaging across classes
+        class_axis (optional | int): axis of y_pred scores to take the argmax over,
+            labels then default to the class channel indices
     Returns:
         precision (np.ndarray): 1D array or scalar values depending on averaging
     """
@@ -62,19 +86,23 @@ def precision(
         ]
     ):
         raise TypeError("y_true and y_pred must be numpy arrays")
-    if y_true.shape != y_pred.shape:
+    if class_axis is None and y_true.shape != y_pred.shape:
         raise ShapeError("y_true and y_pred must be same shape")
     zero_division = ZeroDivision(zero_division)
     average = AverageType(average)
 
-    if labels is None:
-        labels = np.array(
-            sorted(list(_unique(y_true).union(_unique(y_pred)))), dtype=y_true.dtype
-        )
-    elif isinstance(labels, list):
-        labels = np.array(labels, dtype=y_true.dtype)
-
-    x = _precision(y_true, y_pred, labels)
+    if class_axis is not None:
+        labels, x = _reqs_from_scores(y_true, y_pred, labels, class_axis)
+        x = x[:, [0, 1]]
+    else:
+        if labels is None:
+            labels = np.array(
+                sorted(list(_unique(y_true).union(_unique(y_pred)))),
+                dtype=y_true.dtype,
+            )
+        elif isinstance(labels, list):
+            labels = np.array(labels, dtype=y_true.dtype)
+        x = _precision(y_true, y_pred, labels)
     zero_handle = _get_zero_handler(zero_division)
     with np.errstate(divide="ignore", invalid="ignore"):
         if average == AverageType.NONE:
@@ -92,16 +120,20 @@ def recall(
     labels: Optional[Union[List, np.ndarray]] = None,
     zero_division: ZeroDivision = ZeroDivision.NONE,
     average: AverageType = AverageType.NONE,
+    class_axis: Optional[int] = None,
 ) -> Result:
     """Multi-class calculation of recall
 
     Args:
         y_true (np.ndarray): array of true values (must be bool or int types)
-        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types) or
+            array of scores (must be float types) when class_axis is given
         labels (optional | list or np.ndarray):
             labels to calculate confusion matrix for (must be bool or int types)
         zero_division (optional | str): strategy to handle division by 0
         average (optional | str): strategy for averaging across classes
+        class_axis (optional | int): axis of y_pred scores to take the argmax over,
+            labels then default to the class channel indices
     Returns:
         recall (np.ndarray): 1D array or scalar values depending on averaging
     """
@@ -112,19 +144,23 @@ def recall(
         ]
     ):
         raise TypeError("y_true and y_pred must be numpy arrays")
-    if y_true.shape != y_pred.shape:
+    if class_axis is None and y_true.shape != y_pred.shape:
         raise ShapeError("y_true and y_pred must be same shape")
     zero_division = ZeroDivision(zero_division)
     average = AverageType(average)
 
-    if labels is None:
-        labels = np.array(
-            sorted(list(_unique(y_true).union(_unique(y_pred)))), dtype=y_true.dtype
-        )
-    elif isinstance(labels, list):
-        labels = np.array(labels, dtype=y_true.dtype)
-
-    x = _recall(y_true, y_pred, labels)
+    if class_axis is not None:
+        labels, x = _reqs_from_scores(y_true, y_pred, labels, class_axis)
+        x = x[:, [0, 2]]
+    else:
+        if labels is None:
+            labels = np.array(
+                sorted(list(_unique(y_true).union(_unique(y_pred)))),
+                dtype=y_true.dtype,
+            )
+        elif isinstance(labels, list):
+            labels = np.array(labels, dtype=y_true.dtype)
+        x = _recall(y_true, y_pred, labels)
     zero_handle = _get_zero_handler(zero_division)
     with np.errstate(divide="ignore", invalid="ignore"):
         if average == AverageType.NONE:
@@ -142,16 +178,20 @@ def f1_score(
     labels: Optional[Union[List, np.ndarray]] = None,
     zero_division: ZeroDivision = ZeroDivision.NONE,
     average: AverageType = AverageType.NONE,
+    class_axis: Optional[int] = None,
 ) -> Result:
     """Multi-class calculation of f1 score
 
     Args:
         y_true (np.ndarray): array of true values (must be bool or int types)
-        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types) or
+            array of scores (must be float types) when class_axis is given
         labels (optional | list or np.ndarray):
             labels to calculate confusion matrix for (must be bool or int types)
         zero_division (optional | str): strategy to handle division by 0
         average (optional | str): strategy for averaging across classes
+        class_axis (optional | int): axis of y_pred scores to take the argmax over,
+            labels then default to the class channel indices
     Returns:
         f1 score (np.ndarray): 1D array or scalar values depending on averaging
     """
@@ -162,19 +202,22 @@ def f1_score(
         ]
     ):
         raise TypeError("y_true and y_pred must be numpy arrays")
-    if y_true.shape != y_pred.shape:
+    if class_axis is None and y_true.shape != y_pred.shape:
         raise ShapeError("y_true and y_pred must be same shape")
     zero_division = ZeroDivision(zero_division)
     average = AverageType(average)
 
-    if labels is None:
-        labels = np.array(
-            sorted(list(_unique(y_true).union(_unique(y_pred)))), dtype=y_true.dtype
-        )
-    elif isinstance(labels, list):
-        labels = np.array(labels, dtype=y_true.dtype)
-
-    x = _f1_score(y_true, y_pred, labels)
+    if class_axis is not None:
+        labels, x = _reqs_from_scores(y_true, y_pred, labels, class_axis)
+    else:
+        if labels is None:
+            labels = np.array(
+                sorted(list(_unique(y_true).union(_unique(y_pred)))),
+                dtype=y_true.dtype,
+            )
+        elif isinstance(labels, list):
+            labels = np.array(labels, dtype=y_true.dtype)
+        x = _f1_score(y_true, y_pred, labels)
     zero_handle = _get_zero_handler(zero_division)
 
     def f1_from_ext(x, y, z):
@@ -193,25 +236,30 @@ def f1_score(
         return None  # pragma: no cover
 
 
-def stats(
+def fbeta_score(
     y_true: np.ndarray,
     y_pred: np.ndarray,
+    beta: float,
     labels: Optional[Union[List, np.ndarray]] = None,
     zero_division: ZeroDivision = ZeroDivision.NONE,
     average: AverageType = AverageType.NONE,
-) -> Dict[str, Result]:
-    """Multi-class calculation of f1 score
+    class_axis: Optional[int] = None,
+) -> Result:
+    """Multi-class calculation of f-beta score
 
     Args:
         y_true (np.ndarray): array of true values (must be bool or int types)
-        y_pred (np.ndarray): array of pred values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types) or
+            array of scores (must be float types) when class_axis is given
+        beta (float): weight of recall relative to precision (must be >= 0)
         labels (optional | list or np.ndarray):
             labels to calculate confusion matrix for (must be bool or int types)
         zero_division (optional | str): strategy to handle division by 0
         average (optional | str): strategy for averaging across classes
+        class_axis (optional | int): axis of y_pred scores to take the argmax over,
+            labels then default to the class channel indices
     Returns:
-        Dict[str, Result]: dictionary of strings to 1D array or scalar values
-            depending on averaging
+        f-beta score (np.ndarray): 1D array or scalar values depending on averaging
     """
     if not all(
         [
@@ -220,26 +268,57 @@ def stats(
         ]
     ):
         raise TypeError("y_true and y_pred must be numpy arrays")
-    if y_true.shape != y_pred.shape:
+    if class_axis is None and y_true.shape != y_pred.shape:
         raise ShapeError("y_true and y_pred must be same shape")
+    if beta < 0:
+        raise ValueError("beta must be >= 0")
     zero_division = ZeroDivision(zero_division)
     average = AverageType(average)
 
-    if labels is None:
-        labels = np.array(
-            sorted(list(_unique(y_true).union(_unique(y_pred)))), dtype=y_true.dtype
-        )
-    elif isinstance(labels, list):
-        labels = np.array(labels, dtype=y_true.dtype)
+    if class_axis is not None:
+        labels, x = _reqs_from_scores(y_true, y_pred, labels, class_axis)
+    else:
+        if labels is None:
+            labels = np.array(
+                sorted(list(_unique(y_true).union(_unique(y_pred)))),
+                dtype=y_true.dtype,
+            )
+        elif isinstance(labels, list):
+            labels = np.array(labels, dtype=y_true.dtype)
+        x = _f1_score(y_true, y_pred, labels)
+    zero_handle = _get_zero_handler(zero_division)
+
+    def fbeta_from_ext(x, y, z):
+        p, r = x / y, x / z
+        return (1 + beta**2) * p * r / (beta**2 * p + r)
+
+    with np.errstate(divide="ignore", invalid="ignore"):
+        if average == AverageType.NONE:
+            return zero_handle(fbeta_from_ext(x[:, 0], x[:, 1], x[:, 2]))
+        elif average == AverageType.MICRO:
+            return zero_handle(
+                fbeta_from_ext(x[:, 0].sum(), x[:, 1].sum(), x[:, 2].sum())
+            ).item()
+        elif average == AverageType.MACRO:
+            return np.nanmean(fbeta_from_ext(x[:, 0], x[:, 1], x[:, 2])).item()
+        return None  # pragma: no cover
 
-    x = _f1_score(y_true, y_pred, labels)
+
+def _stats(
+    x: np.ndarray,
+    labels: np.ndarray,
+    zero_division: ZeroDivision,
+    average: AverageType,
+    ci: Optional[IntervalType],
+    confidence: float,
+) -> Stats:
     zero_handle = _get_zero_handler(zero_division)
 
     def f1_from_ext(x, y, z):
         p, r = x / y, x / z
         return 2 * p * r / (p + r)
 
-    stats: Dict[str, Result] = dict()
+    stats: Stats = dict()
 
     # precision
     with np.errstate(divide="ignore", invalid="ignore"):
@@ -287,4 +366,105 @@ def stats(
         stats.update({"labels": labels})
         stats.update({"support": x[:, 2]})  # support total y_true (TP + FN)
 
+    if ci is not None:
+        tp, tp_fp, tp_fn = x[:, 0], x[:, 1], x[:, 2]
+        if average == AverageType.MICRO:
+            tp, tp_fp, tp_fn = tp.sum(), tp_fp.sum(), tp_fn.sum()
+        # a single label prediction is correct when it is a TP of its class
+        correct, total = x[:, 0].sum(), x[:, 2].sum()
+        with np.errstate(divide="ignore", invalid="ignore"):
+            stats.update({"accuracy": zero_handle(correct / total).item()})
+        bounds = [
+            _interval(tp, tp_fp, ci, confidence),
+            _interval(tp, tp_fn, ci, confidence),
+            _interval(correct, total, ci, confidence),
+        ]
+        if average == AverageType.MICRO:
+            bounds[:2] = [tuple(b.tolist()) for b in bounds[:2]]
+        stats.update(
+            {
+                "precision_ci": bounds[0],
+                "recall_ci": bounds[1],
+                "accuracy_ci": tuple(bounds[2].tolist()),
+            }
+        )
+
     return stats
+
+
+def stats(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    average: AverageType = AverageType.NONE,
+    class_axis: Optional[int] = None,
+    ci: Optional[IntervalType] = None,
+    confidence: float = 0.95,
+    groups: Optional[np.ndarray] = None,
+) -> Union[Stats, Dict[Any, Stats]]:
+    """Multi-class calculation of f1 score
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_pred (np.ndarray): array of pred values (must be bool or int types) or
+            array of scores (must be float types) when class_axis is given
+        labels (optional | list or np.ndarray):
+            labels to calculate confusion matrix for (must be bool or int types)
+        zero_division (optional | str): strategy to handle division by 0
+        average (optional | str): strategy for averaging across classes
+        class_axis (optional | int): axis of y_pred scores to take the argmax over,
+            labels then default to the class channel indices
+        ci (optional | str): "wilson", "clopper_pearson" or "agresti_coull" to
+            add accuracy and intervals of precision, recall and accuracy (needs
+            "none" or "micro" averaging)
+        confidence (optional | float): confidence level of the intervals
+        groups (optional | np.ndarray): group id (e.g. site or patient) of every
+            entry of the leading axes of y_true
+    Returns:
+        Dict[str, Result]: dictionary of strings to 1D array or scalar values
+            depending on averaging, intervals are (low, high) or (C, 2) arrays,
+            or a dict of these stats per group id when groups is given
+    """
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if class_axis is None and y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    zero_division = ZeroDivision(zero_division)
+    average = AverageType(average)
+    if ci is not None:
+        ci = IntervalType(ci)
+        if average == AverageType.MACRO:
+            raise ValueError("intervals need none or micro averaging")
+
+    if class_axis is not None:
+        if groups is not None:
+            raise ValueError("groups is not supported with class_axis")
+        labels, x = _reqs_from_scores(y_true, y_pred, labels, class_axis)
+    else:
+        if labels is None:
+            labels = np.array(
+                sorted(list(_unique(y_true).union(_unique(y_pred)))),
+                dtype=y_true.dtype,
+            )
+        elif isinstance(labels, list):
+            labels = np.array(labels, dtype=y_true.dtype)
+        if groups is not None:
+            ids, inverse = _group_indices(groups, y_true.shape)
+            cm = _confusion_matrix_grouped(y_true, y_pred, labels, inverse, len(ids))
+            # same TP, TP + FP and TP + FN columns as `_f1_score` for every group
+            x = np.stack(
+                [np.diagonal(cm, axis1=1, axis2=2), cm.sum(axis=1), cm.sum(axis=2)],
+                axis=-1,
+            )
+            return {
+                g: _stats(x[i], labels, zero_division, average, ci, confidence)
+                for i, g in enumerate(ids.tolist())
+            }
+        x = _f1_score(y_true, y_pred, labels)
+    return _stats(x, labels, zero_division, average, ci, confidence)
@@ -0,0 +1,196 @@
+from enum import Enum
+from typing import Callable
+
+import numpy as np
+
+from ._fast_stats_ext import _multilabel_reqs, _multilabel_sample_reqs
+from .exceptions import ShapeError
+from .multiclass import Result, ZeroDivision, _get_zero_handler
+
+
+class AverageType(Enum):
+    NONE = "none"
+    MICRO = "micro"
+    MACRO = "macro"
+    WEIGHTED = "weighted"
+    SAMPLES = "samples"
+
+
+def _check_indicator_arrays(y_true: np.ndarray, y_pred: np.ndarray):
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    if y_true.ndim < 2:
+        raise ShapeError("y_true and y_pred must be (N, C) indicator arrays")
+
+
+def _precision_from_ext(tp, tp_fp, tp_fn):
+    return tp / tp_fp
+
+
+def _recall_from_ext(tp, tp_fp, tp_fn):
+    return tp / tp_fn
+
+
+def _f1_from_ext(tp, tp_fp, tp_fn):
+    p, r = tp / tp_fp, tp / tp_fn
+    return 2 * p * r / (p + r)
+
+
+def _averaged(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    from_ext: Callable,
+    zero_division: ZeroDivision,
+    average: AverageType,
+) -> Result:
+    zero_handle = _get_zero_handler(zero_division)
+    with np.errstate(divide="ignore", invalid="ignore"):
+        if average == AverageType.SAMPLES:
+            x = _multilabel_sample_reqs(y_true, y_pred)
+            return np.nanmean(zero_handle(from_ext(x[:, 0], x[:, 1], x[:, 2]))).item()
+
+        x = _multilabel_reqs(y_true, y_pred)
+        tp, tp_fp, tp_fn = x[:, 0], x[:, 0] + x[:, 1], x[:, 0] + x[:, 2]
+        if average == AverageType.NONE:
+            return zero_handle(from_ext(tp, tp_fp, tp_fn))
+        elif average == AverageType.MICRO:
+            return zero_handle(from_ext(tp.sum(), tp_fp.sum(), tp_fn.sum())).item()
+        elif average == AverageType.MACRO:
+            return np.nanmean(zero_handle(from_ext(tp, tp_fp, tp_fn))).item()
+        elif average == AverageType.WEIGHTED:
+            values = zero_handle(from_ext(tp, tp_fp, tp_fn))
+            mask = np.isfinite(values)
+            if tp_fn[mask].sum() == 0:
+                return zero_handle(np.nan).item()
+            return np.average(values[mask], weights=tp_fn[mask]).item()
+        return None  # pragma: no cover
+
+
+def multilabel_tp_fp_fn_tn(y_true: np.ndarray, y_pred: np.ndarray) -> np.ndarray:
+    """Multi-label calculation of TP, FP, FN and TN for every label
+
+    Args:
+        y_true (np.ndarray): (N, C) indicator array of true values
+        y_pred (np.ndarray): (N, C) indicator array of pred values
+    Returns:
+        counts (np.ndarray): (C, 4) array of TP, FP, FN and TN per label
+    """
+    _check_indicator_arrays(y_true, y_pred)
+    return _multilabel_reqs(y_true, y_pred)
+
+
+def multilabel_confusion_matrix(y_true: np.ndarray, y_pred: np.ndarray) -> np.ndarray:
+    """Multi-label calculation of a 2x2 confusion matrix per label (matches the
+    `sklearn.metrics.multilabel_confusion_matrix` layout of [[TN, FP], [FN, TP]])
+
+    Args:
+        y_true (np.ndarray): (N, C) indicator array of true values
+        y_pred (np.ndarray): (N, C) indicator array of pred values
+    Returns:
+        confusion matrix (np.ndarray): (C, 2, 2) array of confusion matrices
+    """
+    _check_indicator_arrays(y_true, y_pred)
+    x = _multilabel_reqs(y_true, y_pred)
+    return x[:, [3, 1, 2, 0]].reshape(-1, 2, 2)
+
+
+def multilabel_precision(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    average: AverageType = AverageType.NONE,
+) -> Result:
+    """Multi-label calculation of precision
+
+    Args:
+        y_true (np.ndarray): (N, C) indicator array of true values
+        y_pred (np.ndarray): (N, C) indicator array of pred values
+        zero_division (optional | str): strategy to handle division by 0
+        average (optional | str): strategy for averaging across labels or samples
+    Returns:
+        precision (np.ndarray): 1D array or scalar values depending on averaging
+    """
+    _check_indicator_arrays(y_true, y_pred)
+    zero_division = ZeroDivision(zero_division)
+    average = AverageType(average)
+    return _averaged(y_true, y_pred, _precision_from_ext, zero_division, average)
+
+
+def multilabel_recall(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    average: AverageType = AverageType.NONE,
+) -> Result:
+    """Multi-label calculation of recall
+
+    Args:
+        y_true (np.ndarray): (N, C) indicator array of true values
+        y_pred (np.ndarray): (N, C) indicator array of pred values
+        zero_division (optional | str): strategy to handle division by 0
+        average (optional | str): strategy for averaging across labels or samples
+    Returns:
+        recall (np.ndarray): 1D array or scalar values depending on averaging
+    """
+    _check_indicator_arrays(y_true, y_pred)
+    zero_division = ZeroDivision(zero_division)
+    average = AverageType(average)
+    return _averaged(y_true, y_pred, _recall_from_ext, zero_division, average)
+
+
+def multilabel_f1_score(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    average: AverageType = AverageType.NONE,
+) -> Result:
+    """Multi-label calculation of f1 score
+
+    Args:
+        y_true (np.ndarray): (N, C) indicator array of true values
+        y_pred (np.ndarray): (N, C) indicator array of pred values
+        zero_division (optional | str): strategy to handle division by 0
+        average (optional | str): strategy for averaging across labels or samples
+    Returns:
+        f1 score (np.ndarray): 1D array or scalar values depending on averaging
+    """
+    _check_indicator_arrays(y_true, y_pred)
+    zero_division = ZeroDivision(zero_division)
+    average = AverageType(average)
+    return _averaged(y_true, y_pred, _f1_from_ext, zero_division, average)
+
+
+def hamming_loss(y_true: np.ndarray, y_pred: np.ndarray) -> float:
+    """Multi-label calculation of the fraction of wrongly predicted labels
+
+    Args:
+        y_true (np.ndarray): (N, C) indicator array of true values
+        y_pred (np.ndarray): (N, C) indicator array of pred values
+    Returns:
+        hamming loss (float): (FP + FN) / (N * C)
+    """
+    _check_indicator_arrays(y_true, y_pred)
+    x = _multilabel_reqs(y_true, y_pred)
+    return float((x[:, 1].sum() + x[:, 2].sum()) / y_true.size)
+
+
+def subset_accuracy(y_true: np.ndarray, y_pred: np.ndarray) -> float:
+    """Multi-label calculation of exact-match accuracy (every label of a sample
+    must be predicted correctly)
+
+    Args:
+        y_true (np.ndarray): (N, C) indicator array of true values
+        y_pred (np.ndarray): (N, C) indicator array of pred values
+    Returns:
+        subset accuracy (float): fraction of samples that match exactly
+    """
+    _check_indicator_arrays(y_true, y_pred)
+    x = _multilabel_sample_reqs(y_true, y_pred)
+    return np.mean(x[:, 1] + x[:, 2] - 2 * x[:, 0] == 0).item()
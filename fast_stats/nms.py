@@ -0,0 +1,116 @@
+from enum import Enum
+from typing import Tuple
+
+import numpy as np
+
+from ._fast_stats_ext import _nms, _soft_nms
+from .boxes import BoxFormat, _to_xyxy
+from .exceptions import ShapeError
+
+
+class SoftNMSMethod(Enum):
+    LINEAR = "linear"
+    GAUSSIAN = "gaussian"
+
+
+def _as_xyxy(
+    boxes: np.ndarray, scores: np.ndarray, box_format: BoxFormat
+) -> Tuple[np.ndarray, np.ndarray]:
+    if not all(
+        [
+            isinstance(boxes, np.ndarray),
+            isinstance(scores, np.ndarray),
+        ]
+    ):
+        raise TypeError("boxes and scores must be numpy arrays")
+    if boxes.ndim != 2 or boxes.shape[1] != 4:
+        raise ShapeError("boxes must have shape (N, 4)")
+    if scores.shape != (boxes.shape[0],):
+        raise ShapeError("scores must have shape (N,)")
+    box_format = BoxFormat(box_format)
+
+    boxes = _to_xyxy(np.ascontiguousarray(boxes, dtype=np.float64), box_format)
+    return boxes, np.ascontiguousarray(scores, dtype=np.float64)
+
+
+def nms(
+    boxes: np.ndarray,
+    scores: np.ndarray,
+    iou_threshold: float,
+    format: BoxFormat = BoxFormat.XYXY,
+) -> np.ndarray:
+    """Non-maximum suppression, drops every box that has an IoU > iou_threshold
+    with a kept box of higher score
+
+    Args:
+        boxes (np.ndarray): (N, 4) array of boxes
+        scores (np.ndarray): (N,) array of box scores
+        iou_threshold (float): IoU above which boxes are suppressed
+        format (optional | str): box coordinates as "xyxy", "xywh" or "cxcywh"
+    Returns:
+        keep (np.ndarray): indices of the kept boxes by descending score
+    """
+    return _nms(*_as_xyxy(boxes, scores, format), None, float(iou_threshold))
+
+
+def batched_nms(
+    boxes: np.ndarray,
+    scores: np.ndarray,
+    labels: np.ndarray,
+    iou_threshold: float,
+    format: BoxFormat = BoxFormat.XYXY,
+) -> np.ndarray:
+    """Class-aware non-maximum suppression, boxes only suppress boxes of the same
+    label
+
+    Args:
+        boxes (np.ndarray): (N, 4) array of boxes
+        scores (np.ndarray): (N,) array of box scores
+        labels (np.ndarray): (N,) array of box labels (must be int types)
+        iou_threshold (float): IoU above which boxes are suppressed
+        format (optional | str): box coordinates as "xyxy", "xywh" or "cxcywh"
+    Returns:
+        keep (np.ndarray): indices of the kept boxes by descending score
+    """
+    if not isinstance(labels, np.ndarray):
+        raise TypeError("labels must be a numpy array")
+    if labels.shape != scores.shape:
+        raise ShapeError("labels must have shape (N,)")
+    labels = np.ascontiguousarray(labels, dtype=np.int64)
+    return _nms(*_as_xyxy(boxes, scores, format), labels, float(iou_threshold))
+
+
+def soft_nms(
+    boxes: np.ndarray,
+    scores: np.ndarray,
+    iou_threshold: float = 0.3,
+    sigma: float = 0.5,
+    score_threshold: float = 0.001,
+    method: SoftNMSMethod = SoftNMSMethod.GAUSSIAN,
+    format: BoxFormat = BoxFormat.XYXY,
+) -> Tuple[np.ndarray, np.ndarray]:
+    """Soft non-maximum suppression, instead of dropping overlapping boxes their
+    scores decay with the IoU to every kept box until below score_threshold
+
+    Args:
+        boxes (np.ndarray): (N, 4) array of boxes
+        scores (np.ndarray): (N,) array of box scores
+        iou_threshold (optional | float): IoU above which scores decay linearly
+        sigma (optional | float): width of the gaussian decay exp(-IoU^2 / sigma)
+        score_threshold (optional | float): boxes below this score are dropped
+        method (optional | str): score decay of "linear" or "gaussian"
+        format (optional | str): box coordinates as "xyxy", "xywh" or "cxcywh"
+    Returns:
+        Tuple[np.ndarray, np.ndarray]: indices of the kept boxes in order of
+            selection and their decayed scores
+    """
+    method = SoftNMSMethod(method)
+    if method == SoftNMSMethod.GAUSSIAN and sigma <= 0:
+        raise ValueError("sigma must be > 0")
+    return _soft_nms(
+        *_as_xyxy(boxes, scores, format),
+        float(iou_threshold),
+        float(sigma),
+        float(score_threshold),
+        method == SoftNMSMethod.LINEAR,
+    )
@@ -0,0 +1,83 @@
+from typing import Dict, List, Optional, Union
+
+import numpy as np
+
+from ._fast_stats_ext import _panoptic_reqs
+from .exceptions import ShapeError
+from .multiclass import AverageType, Result, ZeroDivision, _get_zero_handler
+
+
+def panoptic_quality(
+    y_true_instances: np.ndarray,
+    y_true_classes: np.ndarray,
+    y_pred_instances: np.ndarray,
+    y_pred_classes: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    void_label: Optional[int] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    average: AverageType = AverageType.NONE,
+) -> Dict[str, Result]:
+    """Multi-class calculation of panoptic quality (PQ), segmentation quality (SQ)
+    and recognition quality (RQ), a segment is a (class, instance id) pair and
+    segments of the same class match at IoU > 0.5
+
+    Args:
+        y_true_instances (np.ndarray): array of true instance ids (must be int types)
+        y_true_classes (np.ndarray): array of true classes (must be int types)
+        y_pred_instances (np.ndarray): array of pred instance ids (must be int types)
+        y_pred_classes (np.ndarray): array of pred classes (must be int types)
+        labels (optional | list or np.ndarray):
+            classes to calculate the stats for (must be int types)
+        void_label (optional | int): class of unlabelled pixels, ignored in y_true
+            and y_pred, pred segments mostly covering it are not counted as FP
+        zero_division (optional | str): strategy to handle division by 0
+        average (optional | str): strategy for averaging across classes
+    Returns:
+        Dict[str, Result]: dictionary of strings to 1D array or scalar values
+            depending on averaging
+    """
+    arrays = [y_true_instances, y_true_classes, y_pred_instances, y_pred_classes]
+    if not all(isinstance(arr, np.ndarray) for arr in arrays):
+        raise TypeError("instance and class maps must be numpy arrays")
+    if any(arr.shape != y_true_instances.shape for arr in arrays):
+        raise ShapeError("instance and class maps must be same shape")
+    zero_division = ZeroDivision(zero_division)
+    average = AverageType(average)
+
+    if labels is None:
+        labels = np.union1d(np.unique(y_true_classes), np.unique(y_pred_classes))
+        labels = labels[labels != void_label]
+    labels = np.asarray(labels, dtype=np.int64)
+
+    x = _panoptic_reqs(
+        *[np.ascontiguousarray(arr, dtype=np.int64) for arr in arrays],
+        labels,
+        void_label,
+    )
+    tp, fp, fn, iou_sum = x[:, 0], x[:, 1], x[:, 2], x[:, 3]
+    if average == AverageType.MICRO:
+        tp, fp, fn, iou_sum = tp.sum(), fp.sum(), fn.sum(), iou_sum.sum()
+    zero_handle = _get_zero_handler(zero_division)
+
+    with np.errstate(divide="ignore", invalid="ignore"):
+        pq = zero_handle(iou_sum / (tp + 0.5 * fp + 0.5 * fn))
+        sq = zero_handle(iou_sum / tp)
+        rq = zero_handle(tp / (tp + 0.5 * fp + 0.5 * fn))
+
+    if average == AverageType.NONE:
+        return {
+            "pq": pq,
+            "sq": sq,
+            "rq": rq,
+            "labels": labels,
+            "support": (tp + fn).astype(np.int64),  # number of true segments
+        }
+    elif average == AverageType.MICRO:
+        return {"pq": pq.item(), "sq": sq.item(), "rq": rq.item()}
+    elif average == AverageType.MACRO:
+        return {
+            "pq": np.nanmean(pq).item(),
+            "sq": np.nanmean(sq).item(),
+            "rq": np.nanmean(rq).item(),
+        }
+    return None  # pragma: no cover
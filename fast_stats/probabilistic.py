@@ -0,0 +1,226 @@
+from enum import Enum
+from typing import List, Optional, Tuple, Union
+
+import numpy as np
+
+from ._fast_stats_ext import (
+    _brier_sum,
+    _calibration_reqs,
+    _label_indices,
+    _log_loss_sum,
+)
+from .exceptions import ShapeError
+from .quantiles import quantile
+
+
+class BinStrategy(Enum):
+    UNIFORM = "uniform"
+    QUANTILE = "quantile"
+
+
+def _probabilities(
+    y_true: np.ndarray,
+    y_prob: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]],
+    class_axis: Optional[int],
+) -> Tuple[np.ndarray, np.ndarray]:
+    if not all(
+        [
+            isinstance(y_prob, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_prob must be numpy arrays")
+
+    if class_axis is None:
+        if y_true.shape != y_prob.shape:
+            raise ShapeError("y_true and y_prob must be same shape")
+        if labels is None:
+            labels = np.array([0, 1], dtype=y_true.dtype)
+        else:
+            labels = np.array(labels, dtype=y_true.dtype)
+        if len(labels) != 2:
+            raise ValueError("labels must be the negative and positive label")
+        prob = y_prob.reshape(-1, 1)
+    else:
+        if not -y_prob.ndim <= class_axis < y_prob.ndim:
+            raise ValueError("class_axis out of range of y_prob dimensions")
+        class_axis = class_axis % y_prob.ndim
+        if np.delete(y_prob.shape, class_axis).tolist() != list(y_true.shape):
+            raise ShapeError("y_prob must be y_true shape with an extra class axis")
+        n_classes = y_prob.shape[class_axis]
+        if labels is None:
+            labels = np.arange(n_classes, dtype=y_true.dtype)
+        else:
+            labels = np.array(labels, dtype=y_true.dtype)
+        if len(labels) != n_classes:
+            raise ValueError("labels must have one entry per class_axis channel")
+        prob = np.moveaxis(y_prob, class_axis, -1).reshape(-1, n_classes)
+
+    # keep float32 arrays as they are, everything else is computed in float64
+    dtype = np.float32 if prob.dtype == np.float32 else np.float64
+    indices = _label_indices(y_true, labels).ravel()
+    return indices, np.ascontiguousarray(prob, dtype=dtype)
+
+
+def _calibration_from_ext(
+    y_true: np.ndarray,
+    y_prob: np.ndarray,
+    n_bins: int,
+    strategy: BinStrategy,
+    labels: Optional[Union[List, np.ndarray]],
+    class_axis: Optional[int],
+) -> np.ndarray:
+    if n_bins < 1:
+        raise ValueError("n_bins must be >= 1")
+    strategy = BinStrategy(strategy)
+    indices, prob = _probabilities(y_true, y_prob, labels, class_axis)
+
+    if strategy == BinStrategy.UNIFORM:
+        edges = np.linspace(0.0, 1.0, n_bins + 1)
+    else:
+        confidence = prob[:, 0] if prob.shape[1] == 1 else prob.max(axis=1)
+        edges = quantile(confidence, np.linspace(0.0, 1.0, n_bins + 1))
+    return _calibration_reqs(indices, prob, edges.tolist())
+
+
+def log_loss(
+    y_true: np.ndarray,
+    y_prob: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    class_axis: Optional[int] = None,
+    eps: float = 1e-15,
+) -> float:
+    """Calculation of the log loss (cross-entropy) of predicted probabilities
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_prob (np.ndarray): array of positive class probabilities of the same
+            shape as y_true, or of class probabilities with an extra class axis
+        labels (optional | list or np.ndarray): negative and positive label, or
+            labels of the class_axis channels (defaults to the channel indices)
+        class_axis (optional | int): axis of the class probabilities in y_prob
+        eps (optional | float): probabilities are clipped to [eps, 1 - eps]
+    Returns:
+        float: mean log loss over the samples
+    """
+    indices, prob = _probabilities(y_true, y_prob, labels, class_axis)
+    if len(indices) == 0:
+        return np.nan
+    return _log_loss_sum(indices, prob, eps) / len(indices)
+
+
+def brier_score_loss(
+    y_true: np.ndarray,
+    y_prob: np.ndarray,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    class_axis: Optional[int] = None,
+) -> float:
+    """Calculation of the Brier score, the mean squared error of the positive
+    class probability or of the class probabilities summed over the classes
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_prob (np.ndarray): array of positive class probabilities of the same
+            shape as y_true, or of class probabilities with an extra class axis
+        labels (optional | list or np.ndarray): negative and positive label, or
+            labels of the class_axis channels (defaults to the channel indices)
+        class_axis (optional | int): axis of the class probabilities in y_prob
+    Returns:
+        float: mean Brier score over the samples
+    """
+    indices, prob = _probabilities(y_true, y_prob, labels, class_axis)
+    if len(indices) == 0:
+        return np.nan
+    return _brier_sum(indices, prob) / len(indices)
+
+
+def calibration_curve(
+    y_true: np.ndarray,
+    y_prob: np.ndarray,
+    n_bins: int = 10,
+    strategy: BinStrategy = BinStrategy.UNIFORM,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    class_axis: Optional[int] = None,
+) -> Tuple[np.ndarray, np.ndarray]:
+    """Calculation of the reliability diagram: the fraction of positives against
+    the mean positive class probability per bin, or the accuracy against the mean
+    top class probability when class_axis is given. Empty bins are dropped.
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_prob (np.ndarray): array of positive class probabilities of the same
+            shape as y_true, or of class probabilities with an extra class axis
+        n_bins (optional | int): number of bins
+        strategy (optional | str): "uniform" bins of equal width in [0, 1] or
+            "quantile" bins with an equal number of samples
+        labels (optional | list or np.ndarray): negative and positive label, or
+            labels of the class_axis channels (defaults to the channel indices)
+        class_axis (optional | int): axis of the class probabilities in y_prob
+    Returns:
+        Tuple[np.ndarray, np.ndarray]: true and predicted probability per bin
+    """
+    x = _calibration_from_ext(y_true, y_prob, n_bins, strategy, labels, class_axis)
+    x = x[x[:, 0] > 0]
+    return x[:, 2] / x[:, 0], x[:, 1] / x[:, 0]
+
+
+def expected_calibration_error(
+    y_true: np.ndarray,
+    y_prob: np.ndarray,
+    n_bins: int = 10,
+    strategy: BinStrategy = BinStrategy.UNIFORM,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    class_axis: Optional[int] = None,
+) -> float:
+    """Calculation of the expected calibration error (ECE), the gap between the
+    true and predicted probability of every bin weighted by its samples
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_prob (np.ndarray): array of positive class probabilities of the same
+            shape as y_true, or of class probabilities with an extra class axis
+        n_bins (optional | int): number of bins
+        strategy (optional | str): "uniform" bins of equal width in [0, 1] or
+            "quantile" bins with an equal number of samples
+        labels (optional | list or np.ndarray): negative and positive label, or
+            labels of the class_axis channels (defaults to the channel indices)
+        class_axis (optional | int): axis of the class probabilities in y_prob
+    Returns:
+        float: ECE
+    """
+    x = _calibration_from_ext(y_true, y_prob, n_bins, strategy, labels, class_axis)
+    with np.errstate(divide="ignore", invalid="ignore"):
+        return (np.abs(x[:, 2] - x[:, 1]).sum() / x[:, 0].sum()).item()
+
+
+def maximum_calibration_error(
+    y_true: np.ndarray,
+    y_prob: np.ndarray,
+    n_bins: int = 10,
+    strategy: BinStrategy = BinStrategy.UNIFORM,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    class_axis: Optional[int] = None,
+) -> float:
+    """Calculation of the maximum calibration error (MCE), the largest gap between
+    the true and predicted probability of a non-empty bin
+
+    Args:
+        y_true (np.ndarray): array of true values (must be bool or int types)
+        y_prob (np.ndarray): array of positive class probabilities of the same
+            shape as y_true, or of class probabilities with an extra class axis
+        n_bins (optional | int): number of bins
+        strategy (optional | str): "uniform" bins of equal width in [0, 1] or
+            "quantile" bins with an equal number of samples
+        labels (optional | list or np.ndarray): negative and positive label, or
+            labels of the class_axis channels (defaults to the channel indices)
+        class_axis (optional | int): axis of the class probabilities in y_prob
+    Returns:
+        float: MCE
+    """
+    prob_true, prob_pred = calibration_curve(
+        y_true, y_prob, n_bins, strategy, labels, class_axis
+    )
+    if prob_true.size == 0:
+        return np.nan
+    return np.abs(prob_true - prob_pred).max().item()
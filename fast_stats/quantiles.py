@@ -0,0 +1,261 @@
+from enum import Enum
+from typing import Optional, Sequence, Tuple, Union
+
+import numpy as np
+
+from ._fast_stats_ext import (
+    _histogram,
+    _moments,
+    _quantile,
+    _tdigest_merge,
+    _tdigest_quantile,
+    _tdigest_update,
+)
+from .descriptive import Stat, _as_float, _item, _normalize_axis
+
+Bins = Union[int, str, Sequence[float], np.ndarray]
+
+
+class QuantileMethod(Enum):
+    LINEAR = "linear"
+    LOWER = "lower"
+    HIGHER = "higher"
+    MIDPOINT = "midpoint"
+
+
+class BinMethod(Enum):
+    AUTO = "auto"
+    FD = "fd"
+    STURGES = "sturges"
+    SQRT = "sqrt"
+
+
+def _as_quantiles(q: Union[float, Sequence[float], np.ndarray]) -> np.ndarray:
+    qs = np.asarray(q, dtype=np.float64)
+    if not np.all((qs >= 0) & (qs <= 1)):
+        raise ValueError("quantiles must be in the range [0, 1]")
+    return qs
+
+
+def quantile(
+    arr: np.ndarray,
+    q: Union[float, Sequence[float], np.ndarray],
+    axis: Optional[int] = None,
+    method: QuantileMethod = QuantileMethod.LINEAR,
+) -> Stat:
+    """Calculation of exact quantiles by selection (no full sort), matching
+    `np.quantile`. Arrays (or lanes) containing NaN give NaN.
+
+    Args:
+        arr (np.ndarray): array of values
+        q (float | Sequence[float]): quantile(s) in [0, 1]
+        axis (optional | int): axis to reduce, all axes when None
+        method (optional | str): "linear", "lower", "higher" or "midpoint"
+            interpolation between the two closest values
+    Returns:
+        Stat: float, or array with the quantiles on the first axis followed by
+            the axes that are not reduced
+    """
+    arr = _as_float(arr)
+    qs = _as_quantiles(q)
+    method = QuantileMethod(method)
+
+    res = _quantile(
+        arr, qs.ravel().tolist(), _normalize_axis(axis, arr.ndim), method.value
+    )
+    res = np.moveaxis(res, -1, 0).reshape(qs.shape + res.shape[:-1])
+    return _item(res)
+
+
+class TDigest:
+    """Mergeable t-digest sketch of approximate quantiles for arrays too large to
+    sort. Memory stays at about `compression` centroids and the tails are kept
+    most accurately, the minimum and maximum are exact.
+
+    Args:
+        compression (optional | float): accuracy vs size trade off of the sketch
+    """
+
+    def __init__(self, compression: float = 100.0):
+        if compression <= 0:
+            raise ValueError("compression must be positive")
+        self.compression = compression
+        self.centroids = np.empty((0, 2))
+        self.min = np.inf
+        self.max = -np.inf
+
+    @property
+    def count(self) -> int:
+        return int(self.centroids[:, 1].sum())
+
+    def update(self, arr: np.ndarray) -> "TDigest":
+        """Add the values of an array, NaNs are ignored
+
+        Args:
+            arr (np.ndarray): array of values
+        Returns:
+            TDigest: the sketch itself
+        """
+        self.centroids, min_, max_ = _tdigest_update(
+            self.centroids, _as_float(arr), self.compression
+        )
+        self.min, self.max = min(self.min, min_), max(self.max, max_)
+        return self
+
+    def merge(self, other: "TDigest") -> "TDigest":
+        """Add the values summarised by another sketch
+
+        Args:
+            other (TDigest): sketch to merge
+        Returns:
+            TDigest: the sketch itself
+        """
+        self.centroids = _tdigest_merge(
+            self.centroids, other.centroids, self.compression
+        )
+        self.min, self.max = min(self.min, other.min), max(self.max, other.max)
+        return self
+
+    def quantile(self, q: Union[float, Sequence[float], np.ndarray]) -> Stat:
+        """Approximate quantiles of the values added so far
+
+        Args:
+            q (float | Sequence[float]): quantile(s) in [0, 1]
+        Returns:
+            Stat: float, or array of the same shape as q
+        """
+        qs = _as_quantiles(q)
+        res = _tdigest_quantile(self.centroids, self.min, self.max, qs.ravel().tolist())
+        return _item(res.reshape(qs.shape))
+
+
+def histogram_bin_edges(
+    arr: np.ndarray,
+    bins: Bins = 10,
+    range: Optional[Tuple[float, float]] = None,
+) -> np.ndarray:
+    """Calculation of histogram bin edges like `np.histogram_bin_edges`
+
+    Args:
+        arr (np.ndarray): array of values
+        bins (optional | int | str | Sequence[float]): number of equal width bins,
+            "auto", "fd", "sturges" or "sqrt" to pick the width from the data, or
+            the edges themselves
+        range (optional | Tuple[float, float]): lower and upper edge, the min and
+            max of arr when None
+    Returns:
+        np.ndarray: 1D array of increasing edges
+    """
+    if not isinstance(bins, (int, np.integer, str)):
+        edges = np.asarray(bins, dtype=np.float64)
+        if edges.ndim != 1 or edges.size < 2 or np.any(np.diff(edges) <= 0):
+            raise ValueError("bins must be at least 2 strictly increasing edges")
+        return edges
+
+    arr = _as_float(arr)
+    n, mean, _, _, _, min_, max_ = _moments(arr, None)
+    if range is not None:
+        first, last = float(range[0]), float(range[1])
+        if first > last:
+            raise ValueError("range must be increasing")
+    elif n == 0:
+        first, last = 0.0, 1.0
+    elif not np.isfinite(mean):
+        raise ValueError("autodetected range is not finite")
+    else:
+        first, last = min_, max_
+    if first == last:
+        first, last = first - 0.5, last + 0.5
+
+    if isinstance(bins, str):
+        method = BinMethod(bins)
+        if range is not None:
+            # like numpy the width only comes from the values inside the range
+            arr = arr[(arr >= first) & (arr <= last)]
+            n, _, _, _, _, min_, max_ = _moments(arr, None)
+        width = 0.0
+        if n > 0:
+            ptp = max_ - min_
+            width = ptp / (np.log2(n) + 1)
+            if method == BinMethod.SQRT:
+                width = ptp / np.sqrt(n)
+            elif method in (BinMethod.AUTO, BinMethod.FD):
+                q25, q75 = _quantile(arr, [0.25, 0.75], None, "linear")
+                fd = 2 * (q75 - q25) * n ** (-1 / 3)
+                if method == BinMethod.FD:
+                    width = fd
+                elif fd > 0:
+                    width = min(width, fd)
+        bins = int(np.ceil((last - first) / width)) if width > 0 else 1
+    if bins < 1:
+        raise ValueError("bins must be a positive integer")
+    return np.linspace(first, last, bins + 1)
+
+
+def histogram(
+    arr: np.ndarray,
+    bins: Bins = 10,
+    range: Optional[Tuple[float, float]] = None,
+) -> Tuple[np.ndarray, np.ndarray]:
+    """Calculation of a histogram like `np.histogram`, the last bin includes its
+    right edge and values outside the edges are not counted
+
+    Args:
+        arr (np.ndarray): array of values
+        bins (optional | int | str | Sequence[float]): number of equal width bins,
+            "auto", "fd", "sturges" or "sqrt" to pick the width from the data, or
+            the edges themselves
+        range (optional | Tuple[float, float]): lower and upper edge, the min and
+            max of arr when None
+    Returns:
+        Tuple[np.ndarray, np.ndarray]: counts per bin and the bin edges
+    """
+    edges = histogram_bin_edges(arr, bins, range)
+    return _histogram(_as_float(arr), edges.tolist()), edges
+
+
+class Histogram:
+    """Streaming histogram with fixed bins, mergeable when the edges are equal
+
+    Args:
+        bins (optional | int | Sequence[float]): number of equal width bins over
+            range, or the edges themselves
+        range (optional | Tuple[float, float]): lower and upper edge, needed when
+            bins is a number
+    """
+
+    def __init__(
+        self,
+        bins: Union[int, Sequence[float], np.ndarray] = 10,
+        range: Optional[Tuple[float, float]] = None,
+    ):
+        if isinstance(bins, str):
+            raise ValueError("automatic bins need all the data, use histogram")
+        if isinstance(bins, (int, np.integer)) and range is None:
+            raise ValueError("range must be given for a number of bins")
+        self.edges = histogram_bin_edges(np.empty(0), bins, range)
+        self.counts = np.zeros(len(self.edges) - 1, dtype=np.int64)
+
+    def update(self, arr: np.ndarray) -> "Histogram":
+        """Add the values of an array
+
+        Args:
+            arr (np.ndarray): array of values
+        Returns:
+            Histogram: the histogram itself
+        """
+        self.counts += _histogram(_as_float(arr), self.edges.tolist())
+        return self
+
+    def merge(self, other: "Histogram") -> "Histogram":
+        """Add the counts of another histogram
+
+        Args:
+            other (Histogram): histogram with the same edges
+        Returns:
+            Histogram: the histogram itself
+        """
+        if not np.array_equal(self.edges, other.edges):
+            raise ValueError("histograms must have the same edges")
+        self.counts += other.counts
+        return self
@@ -0,0 +1,286 @@
+from typing import Dict, Optional, Tuple
+
+import numpy as np
+
+from ._fast_stats_ext import _ranking_reqs, _ranking_reqs_grouped
+from .exceptions import ShapeError
+from .multiclass import Result, ZeroDivision, _get_zero_handler
+
+
+def _ranking_from_ext(
+    relevance: np.ndarray,
+    scores: np.ndarray,
+    k: Optional[int],
+    query_ids: Optional[np.ndarray],
+) -> np.ndarray:
+    if not all(
+        [
+            isinstance(relevance, np.ndarray),
+            isinstance(scores, np.ndarray),
+        ]
+    ):
+        raise TypeError("relevance and scores must be numpy arrays")
+    if relevance.shape != scores.shape:
+        raise ShapeError("relevance and scores must be same shape")
+    if k is not None and k < 1:
+        raise ValueError("k must be >= 1")
+    relevance = np.ascontiguousarray(relevance, dtype=np.float64)
+    scores = np.ascontiguousarray(scores, dtype=np.float64)
+
+    if query_ids is None:
+        if relevance.ndim != 2:
+            raise ShapeError("relevance and scores must be 2D (Q, N)")
+        return _ranking_reqs(relevance, scores, k)
+    query_ids = np.ascontiguousarray(query_ids, dtype=np.int64)
+    if relevance.ndim != 1 or query_ids.shape != relevance.shape:
+        raise ShapeError("relevance, scores and query_ids must be 1D (N,)")
+    _, x = _ranking_reqs_grouped(relevance, scores, query_ids, k)
+    return x
+
+
+def _per_query(
+    values: Tuple[np.ndarray, np.ndarray],
+    zero_division: ZeroDivision,
+    per_query: bool,
+) -> Result:
+    zero_handle = _get_zero_handler(ZeroDivision(zero_division))
+    with np.errstate(divide="ignore", invalid="ignore"):
+        res = zero_handle(values[0] / values[1])
+        if per_query:
+            return res
+        return zero_handle(np.nanmean(res) if res.size else np.nan).item()
+
+
+def _ndcg(x: np.ndarray) -> Tuple[np.ndarray, np.ndarray]:
+    return x[:, 0], x[:, 1]
+
+
+def _reciprocal_rank(x: np.ndarray) -> Tuple[np.ndarray, np.ndarray]:
+    # undefined for queries without relevant items
+    return x[:, 2], np.minimum(x[:, 5], 1)
+
+
+def _average_precision(x: np.ndarray) -> Tuple[np.ndarray, np.ndarray]:
+    return x[:, 3], np.minimum(x[:, 5], x[:, 6])
+
+
+def _hit_rate(x: np.ndarray) -> Tuple[np.ndarray, np.ndarray]:
+    return (x[:, 4] > 0).astype(np.float64), np.minimum(x[:, 5], 1)
+
+
+def _precision_at_k(x: np.ndarray) -> Tuple[np.ndarray, np.ndarray]:
+    return x[:, 4], x[:, 6]
+
+
+def _recall_at_k(x: np.ndarray) -> Tuple[np.ndarray, np.ndarray]:
+    return x[:, 4], x[:, 5]
+
+
+def ndcg_score(
+    relevance: np.ndarray,
+    scores: np.ndarray,
+    k: Optional[int] = None,
+    query_ids: Optional[np.ndarray] = None,
+    exponential_gain: bool = False,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    per_query: bool = False,
+) -> Result:
+    """Calculation of the normalized discounted cumulative gain (NDCG@k), items
+    with tied scores keep their input order
+
+    Args:
+        relevance (np.ndarray): (Q, N) array of graded relevance (0 is not
+            relevant), or (N,) array when query_ids are given
+        scores (np.ndarray): array of scores of the same shape as relevance
+        k (optional | int): number of top ranked items considered, all if None
+        query_ids (optional | np.ndarray): (N,) array of the query of every item
+            for queries with different numbers of items
+        exponential_gain (optional | bool): use 2^relevance - 1 as the gain
+        zero_division (optional | str): strategy to handle division by 0
+        per_query (optional | bool): return the NDCG of every query (in order of
+            sorted query ids)
+    Returns:
+        Result: mean NDCG over the queries, or 1D array of NDCG per query
+    """
+    if exponential_gain and isinstance(relevance, np.ndarray):
+        relevance = np.exp2(relevance.astype(np.float64)) - 1
+    x = _ranking_from_ext(relevance, scores, k, query_ids)
+    return _per_query(_ndcg(x), zero_division, per_query)
+
+
+def mean_reciprocal_rank(
+    relevance: np.ndarray,
+    scores: np.ndarray,
+    k: Optional[int] = None,
+    query_ids: Optional[np.ndarray] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    per_query: bool = False,
+) -> Result:
+    """Calculation of the mean reciprocal rank (MRR@k) of the first relevant item,
+    0 when it is not in the top k
+
+    Args:
+        relevance (np.ndarray): (Q, N) array of relevance (> 0 is relevant), or
+            (N,) array when query_ids are given
+        scores (np.ndarray): array of scores of the same shape as relevance
+        k (optional | int): number of top ranked items considered, all if None
+        query_ids (optional | np.ndarray): (N,) array of the query of every item
+            for queries with different numbers of items
+        zero_division (optional | str): strategy to handle queries without
+            relevant items
+        per_query (optional | bool): return the reciprocal rank of every query
+            (in order of sorted query ids)
+    Returns:
+        Result: MRR over the queries, or 1D array of reciprocal ranks per query
+    """
+    x = _ranking_from_ext(relevance, scores, k, query_ids)
+    return _per_query(_reciprocal_rank(x), zero_division, per_query)
+
+
+def mean_average_precision(
+    relevance: np.ndarray,
+    scores: np.ndarray,
+    k: Optional[int] = None,
+    query_ids: Optional[np.ndarray] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    per_query: bool = False,
+) -> Result:
+    """Calculation of the mean average precision (MAP@k), the precision at every
+    relevant item in the top k summed and divided by min(k, relevant items)
+
+    Args:
+        relevance (np.ndarray): (Q, N) array of relevance (> 0 is relevant), or
+            (N,) array when query_ids are given
+        scores (np.ndarray): array of scores of the same shape as relevance
+        k (optional | int): number of top ranked items considered, all if None
+        query_ids (optional | np.ndarray): (N,) array of the query of every item
+            for queries with different numbers of items
+        zero_division (optional | str): strategy to handle queries without
+            relevant items
+        per_query (optional | bool): return the average precision of every query
+            (in order of sorted query ids)
+    Returns:
+        Result: MAP over the queries, or 1D array of average precision per query
+    """
+    x = _ranking_from_ext(relevance, scores, k, query_ids)
+    return _per_query(_average_precision(x), zero_division, per_query)
+
+
+def hit_rate(
+    relevance: np.ndarray,
+    scores: np.ndarray,
+    k: Optional[int] = None,
+    query_ids: Optional[np.ndarray] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    per_query: bool = False,
+) -> Result:
+    """Calculation of the hit rate (HR@k), the fraction of queries with a relevant
+    item in the top k
+
+    Args:
+        relevance (np.ndarray): (Q, N) array of relevance (> 0 is relevant), or
+            (N,) array when query_ids are given
+        scores (np.ndarray): array of scores of the same shape as relevance
+        k (optional | int): number of top ranked items considered, all if None
+        query_ids (optional | np.ndarray): (N,) array of the query of every item
+            for queries with different numbers of items
+        zero_division (optional | str): strategy to handle queries without
+            relevant items
+        per_query (optional | bool): return the hit (0 or 1) of every query (in
+            order of sorted query ids)
+    Returns:
+        Result: hit rate over the queries, or 1D array of hits per query
+    """
+    x = _ranking_from_ext(relevance, scores, k, query_ids)
+    return _per_query(_hit_rate(x), zero_division, per_query)
+
+
+def precision_at_k(
+    relevance: np.ndarray,
+    scores: np.ndarray,
+    k: Optional[int] = None,
+    query_ids: Optional[np.ndarray] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    per_query: bool = False,
+) -> Result:
+    """Calculation of precision@k, the fraction of relevant items in the top k
+    (or all items of queries with fewer than k)
+
+    Args:
+        relevance (np.ndarray): (Q, N) array of relevance (> 0 is relevant), or
+            (N,) array when query_ids are given
+        scores (np.ndarray): array of scores of the same shape as relevance
+        k (optional | int): number of top ranked items considered, all if None
+        query_ids (optional | np.ndarray): (N,) array of the query of every item
+            for queries with different numbers of items
+        zero_division (optional | str): strategy to handle division by 0
+        per_query (optional | bool): return the precision of every query (in
+            order of sorted query ids)
+    Returns:
+        Result: mean precision@k over the queries, or 1D array per query
+    """
+    x = _ranking_from_ext(relevance, scores, k, query_ids)
+    return _per_query(_precision_at_k(x), zero_division, per_query)
+
+
+def recall_at_k(
+    relevance: np.ndarray,
+    scores: np.ndarray,
+    k: Optional[int] = None,
+    query_ids: Optional[np.ndarray] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    per_query: bool = False,
+) -> Result:
+    """Calculation of recall@k, the fraction of the relevant items in the top k
+
+    Args:
+        relevance (np.ndarray): (Q, N) array of relevance (> 0 is relevant), or
+            (N,) array when query_ids are given
+        scores (np.ndarray): array of scores of the same shape as relevance
+        k (optional | int): number of top ranked items considered, all if None
+        query_ids (optional | np.ndarray): (N,) array of the query of every item
+            for queries with different numbers of items
+        zero_division (optional | str): strategy to handle queries without
+            relevant items
+        per_query (optional | bool): return the recall of every query (in order
+            of sorted query ids)
+    Returns:
+        Result: mean recall@k over the queries, or 1D array per query
+    """
+    x = _ranking_from_ext(relevance, scores, k, query_ids)
+    return _per_query(_recall_at_k(x), zero_division, per_query)
+
+
+def ranking_stats(
+    relevance: np.ndarray,
+    scores: np.ndarray,
+    k: Optional[int] = None,
+    query_ids: Optional[np.ndarray] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+    per_query: bool = False,
+) -> Dict[str, Result]:
+    """Ranking calculations for NDCG, MRR, MAP, hit rate, precision and recall at
+    k from one pass over the queries
+
+    Args:
+        relevance (np.ndarray): (Q, N) array of graded relevance (> 0 is
+            relevant), or (N,) array when query_ids are given
+        scores (np.ndarray): array of scores of the same shape as relevance
+        k (optional | int): number of top ranked items considered, all if None
+        query_ids (optional | np.ndarray): (N,) array of the query of every item
+            for queries with different numbers of items
+        zero_division (optional | str): strategy to handle division by 0
+        per_query (optional | bool): return the stats of every query (in order of
+            sorted query ids)
+    Returns:
+        Dict[str, Result]: stats as floats, or 1D arrays per query
+    """
+    x = _ranking_from_ext(relevance, scores, k, query_ids)
+    return {
+        "ndcg": _per_query(_ndcg(x), zero_division, per_query),
+        "mrr": _per_query(_reciprocal_rank(x), zero_division, per_query),
+        "map": _per_query(_average_precision(x), zero_division, per_query),
+        "hit_rate": _per_query(_hit_rate(x), zero_division, per_query),
+        "precision": _per_query(_precision_at_k(x), zero_division, per_query),
+        "recall": _per_query(_recall_at_k(x), zero_division, per_query),
+    }
@@ -0,0 +1,255 @@
+from enum import Enum
+from typing import Dict, Optional, Union
+
+import numpy as np
+
+from ._fast_stats_ext import _regression_reqs
+from .exceptions import ShapeError
+
+Score = Union[float, np.ndarray]
+
+
+class MultiOutput(Enum):
+    RAW_VALUES = "raw_values"
+    UNIFORM_AVERAGE = "uniform_average"
+    VARIANCE_WEIGHTED = "variance_weighted"
+
+
+def _reqs(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    sample_weight: Optional[np.ndarray],
+) -> np.ndarray:
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+    if y_true.ndim not in (1, 2):
+        raise ShapeError("y_true and y_pred must be (N,) or (N, D) arrays")
+    if y_true.ndim == 1:
+        y_true, y_pred = y_true[:, np.newaxis], y_pred[:, np.newaxis]
+
+    # keep float32 arrays as they are, everything else is computed in float64
+    dtype = np.result_type(y_true, y_pred, np.float32)
+    if dtype != np.float32:
+        dtype = np.float64
+    y_true = np.ascontiguousarray(y_true, dtype=dtype)
+    y_pred = np.ascontiguousarray(y_pred, dtype=dtype)
+    if sample_weight is not None:
+        sample_weight = np.ascontiguousarray(sample_weight, dtype=np.float64)
+        if sample_weight.shape != (y_true.shape[0],):
+            raise ShapeError("sample_weight must have shape (N,)")
+    return _regression_reqs(y_true, y_pred, sample_weight)
+
+
+def _explained(numerator: np.ndarray, denominator: np.ndarray) -> np.ndarray:
+    # perfect predictions score 1 and any error on a constant y_true scores 0
+    scores = np.ones(len(numerator))
+    valid = (numerator != 0) & (denominator != 0)
+    scores[valid] = 1 - numerator[valid] / denominator[valid]
+    scores[(numerator != 0) & (denominator == 0)] = 0.0
+    return scores
+
+
+def _reduce(
+    scores: np.ndarray,
+    multioutput: MultiOutput,
+    variance: Optional[np.ndarray] = None,
+) -> Score:
+    multioutput = MultiOutput(multioutput)
+    if multioutput == MultiOutput.RAW_VALUES:
+        return scores
+    if multioutput == MultiOutput.VARIANCE_WEIGHTED:
+        if variance is None:
+            raise ValueError("variance_weighted is only supported by r2 and EV")
+        if np.any(variance != 0):
+            return np.average(scores, weights=variance).item()
+    return scores.mean().item()
+
+
+def mean_absolute_error(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    sample_weight: Optional[np.ndarray] = None,
+    multioutput: MultiOutput = MultiOutput.UNIFORM_AVERAGE,
+) -> Score:
+    """Calculation of the mean absolute error
+
+    Args:
+        y_true (np.ndarray): (N,) or (N, D) array of true values
+        y_pred (np.ndarray): (N,) or (N, D) array of pred values
+        sample_weight (optional | np.ndarray): (N,) array of sample weights
+        multioutput (optional | str): "raw_values" or "uniform_average" over D
+    Returns:
+        Score: float, or 1D array of scores per output
+    """
+    x = _reqs(y_true, y_pred, sample_weight)
+    return _reduce(x[:, 1] / x[:, 0], multioutput)
+
+
+def mean_squared_error(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    sample_weight: Optional[np.ndarray] = None,
+    multioutput: MultiOutput = MultiOutput.UNIFORM_AVERAGE,
+) -> Score:
+    """Calculation of the mean squared error
+
+    Args:
+        y_true (np.ndarray): (N,) or (N, D) array of true values
+        y_pred (np.ndarray): (N,) or (N, D) array of pred values
+        sample_weight (optional | np.ndarray): (N,) array of sample weights
+        multioutput (optional | str): "raw_values" or "uniform_average" over D
+    Returns:
+        Score: float, or 1D array of scores per output
+    """
+    x = _reqs(y_true, y_pred, sample_weight)
+    return _reduce(x[:, 2] / x[:, 0], multioutput)
+
+
+def root_mean_squared_error(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    sample_weight: Optional[np.ndarray] = None,
+    multioutput: MultiOutput = MultiOutput.UNIFORM_AVERAGE,
+) -> Score:
+    """Calculation of the root mean squared error
+
+    Args:
+        y_true (np.ndarray): (N,) or (N, D) array of true values
+        y_pred (np.ndarray): (N,) or (N, D) array of pred values
+        sample_weight (optional | np.ndarray): (N,) array of sample weights
+        multioutput (optional | str): "raw_values" or "uniform_average" over D
+    Returns:
+        Score: float, or 1D array of scores per output
+    """
+    x = _reqs(y_true, y_pred, sample_weight)
+    return _reduce(np.sqrt(x[:, 2] / x[:, 0]), multioutput)
+
+
+def median_absolute_error(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    sample_weight: Optional[np.ndarray] = None,
+    multioutput: MultiOutput = MultiOutput.UNIFORM_AVERAGE,
+) -> Score:
+    """Calculation of the median absolute error, the weighted median is the
+    lowest error reaching half of the total weight
+
+    Args:
+        y_true (np.ndarray): (N,) or (N, D) array of true values
+        y_pred (np.ndarray): (N,) or (N, D) array of pred values
+        sample_weight (optional | np.ndarray): (N,) array of sample weights
+        multioutput (optional | str): "raw_values" or "uniform_average" over D
+    Returns:
+        Score: float, or 1D array of scores per output
+    """
+    x = _reqs(y_true, y_pred, sample_weight)
+    return _reduce(x[:, 6], multioutput)
+
+
+def mean_absolute_percentage_error(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    sample_weight: Optional[np.ndarray] = None,
+    multioutput: MultiOutput = MultiOutput.UNIFORM_AVERAGE,
+) -> Score:
+    """Calculation of the mean absolute percentage error as a fraction (not
+    multiplied by 100), |y_true| is clipped to machine epsilon
+
+    Args:
+        y_true (np.ndarray): (N,) or (N, D) array of true values
+        y_pred (np.ndarray): (N,) or (N, D) array of pred values
+        sample_weight (optional | np.ndarray): (N,) array of sample weights
+        multioutput (optional | str): "raw_values" or "uniform_average" over D
+    Returns:
+        Score: float, or 1D array of scores per output
+    """
+    x = _reqs(y_true, y_pred, sample_weight)
+    return _reduce(x[:, 3] / x[:, 0], multioutput)
+
+
+def r2_score(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    sample_weight: Optional[np.ndarray] = None,
+    multioutput: MultiOutput = MultiOutput.UNIFORM_AVERAGE,
+) -> Score:
+    """Calculation of the coefficient of determination (R²)
+
+    Args:
+        y_true (np.ndarray): (N,) or (N, D) array of true values
+        y_pred (np.ndarray): (N,) or (N, D) array of pred values
+        sample_weight (optional | np.ndarray): (N,) array of sample weights
+        multioutput (optional | str): "raw_values", "uniform_average" or
+            "variance_weighted" over D
+    Returns:
+        Score: float, or 1D array of scores per output
+    """
+    x = _reqs(y_true, y_pred, sample_weight)
+    return _reduce(_explained(x[:, 2], x[:, 4]), multioutput, x[:, 4])
+
+
+def explained_variance_score(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    sample_weight: Optional[np.ndarray] = None,
+    multioutput: MultiOutput = MultiOutput.UNIFORM_AVERAGE,
+) -> Score:
+    """Calculation of the explained variance, R² without penalising a constant
+    offset of the predictions
+
+    Args:
+        y_true (np.ndarray): (N,) or (N, D) array of true values
+        y_pred (np.ndarray): (N,) or (N, D) array of pred values
+        sample_weight (optional | np.ndarray): (N,) array of sample weights
+        multioutput (optional | str): "raw_values", "uniform_average" or
+            "variance_weighted" over D
+    Returns:
+        Score: float, or 1D array of scores per output
+    """
+    x = _reqs(y_true, y_pred, sample_weight)
+    return _reduce(_explained(x[:, 5], x[:, 4]), multioutput, x[:, 4])
+
+
+def regression_stats(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    sample_weight: Optional[np.ndarray] = None,
+    multioutput: MultiOutput = MultiOutput.UNIFORM_AVERAGE,
+) -> Dict[str, Score]:
+    """Regression calculations for MAE, MSE, RMSE, median absolute error, MAPE,
+    R² and explained variance from one pass over the arrays
+
+    Args:
+        y_true (np.ndarray): (N,) or (N, D) array of true values
+        y_pred (np.ndarray): (N,) or (N, D) array of pred values
+        sample_weight (optional | np.ndarray): (N,) array of sample weights
+        multioutput (optional | str): "raw_values", "uniform_average" or
+            "variance_weighted" over D (R² and explained variance only, the
+            others are averaged uniformly)
+    Returns:
+        Dict[str, Score]: stats as floats, or 1D arrays of scores per output
+    """
+    x = _reqs(y_true, y_pred, sample_weight)
+    multioutput = MultiOutput(multioutput)
+    uniform = multioutput
+    if multioutput == MultiOutput.VARIANCE_WEIGHTED:
+        uniform = MultiOutput.UNIFORM_AVERAGE
+
+    return {
+        "mae": _reduce(x[:, 1] / x[:, 0], uniform),
+        "mse": _reduce(x[:, 2] / x[:, 0], uniform),
+        "rmse": _reduce(np.sqrt(x[:, 2] / x[:, 0]), uniform),
+        "median_ae": _reduce(x[:, 6], uniform),
+        "mape": _reduce(x[:, 3] / x[:, 0], uniform),
+        "r2": _reduce(_explained(x[:, 2], x[:, 4]), multioutput, x[:, 4]),
+        "explained_variance": _reduce(
+            _explained(x[:, 5], x[:, 4]), multioutput, x[:, 4]
+        ),
+    }
@@ -0,0 +1,84 @@
+from typing import Optional, Sequence, Tuple
+
+import numpy as np
+
+from ._fast_stats_ext import _surface_distances
+from .exceptions import ShapeError
+
+
+def _distances(
+    y_true: np.ndarray, y_pred: np.ndarray, spacing: Optional[Sequence[float]]
+) -> Tuple[np.ndarray, np.ndarray]:
+    if not all(
+        [
+            isinstance(y_pred, np.ndarray),
+            isinstance(y_true, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and y_pred must be numpy arrays")
+    if y_true.shape != y_pred.shape:
+        raise ShapeError("y_true and y_pred must be same shape")
+
+    if spacing is None:
+        spacing = [1.0] * y_true.ndim
+    spacing = [float(s) for s in spacing]
+    if len(spacing) != y_true.ndim:
+        raise ValueError("spacing must have one entry per array dimension")
+    if any(s <= 0 for s in spacing):
+        raise ValueError("spacing must be > 0")
+
+    return _surface_distances(y_true, y_pred, spacing)
+
+
+def hausdorff_distance(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    percentile: float = 100.0,
+    spacing: Optional[Sequence[float]] = None,
+) -> Optional[float]:
+    """Calculation of the (percentile) Hausdorff distance between the boundaries of
+    binary masks, e.g. percentile=95 gives HD95
+
+    Args:
+        y_true (np.ndarray): array of 0/1 values (must be bool or int types)
+        y_pred (np.ndarray): array of 0/1 values (must be bool or int types)
+        percentile (optional | float): percentile of each directed distance set,
+            100 gives the maximum
+        spacing (optional | sequence of floats): element size along every axis
+    Returns:
+        Optional[float]: None if either mask is empty
+    """
+    if not 0 <= percentile <= 100:
+        raise ValueError("percentile must be in [0, 100]")
+    pred_to_true, true_to_pred = _distances(y_true, y_pred, spacing)
+    if len(pred_to_true) == 0 or len(true_to_pred) == 0:
+        return None
+    return max(
+        np.percentile(pred_to_true, percentile).item(),
+        np.percentile(true_to_pred, percentile).item(),
+    )
+
+
+def mean_surface_distance(
+    y_true: np.ndarray,
+    y_pred: np.ndarray,
+    spacing: Optional[Sequence[float]] = None,
+    symmetric: bool = True,
+) -> Optional[float]:
+    """Calculation of the mean distance between the boundaries of binary masks
+
+    Args:
+        y_true (np.ndarray): array of 0/1 values (must be bool or int types)
+        y_pred (np.ndarray): array of 0/1 values (must be bool or int types)
+        spacing (optional | sequence of floats): element size along every axis
+        symmetric (optional | bool): average over both boundaries (ASSD) instead
+            of only the y_pred boundary to the y_true boundary
+    Returns:
+        Optional[float]: None if either mask is empty
+    """
+    pred_to_true, true_to_pred = _distances(y_true, y_pred, spacing)
+    if len(pred_to_true) == 0 or len(true_to_pred) == 0:
+        return None
+    if symmetric:
+        return np.concatenate([pred_to_true, true_to_pred]).mean().item()
+    return pred_to_true.mean().item()
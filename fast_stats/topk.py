@@ -0,0 +1,87 @@
+from typing import List, Optional, Tuple, Union
+
+import numpy as np
+
+from ._fast_stats_ext import _label_indices, _top_k_reqs
+from .exceptions import ShapeError
+from .multiclass import Result, ZeroDivision, _get_zero_handler
+
+
+def _top_k_from_ext(
+    y_true: np.ndarray,
+    scores: np.ndarray,
+    k: int,
+    labels: Optional[Union[List, np.ndarray]],
+) -> Tuple[np.ndarray, np.ndarray]:
+    if not all(
+        [
+            isinstance(y_true, np.ndarray),
+            isinstance(scores, np.ndarray),
+        ]
+    ):
+        raise TypeError("y_true and scores must be numpy arrays")
+    if y_true.ndim != 1 or scores.ndim != 2:
+        raise ShapeError("y_true must be 1D (N,) and scores must be 2D (N, C)")
+    if y_true.shape[0] != scores.shape[0]:
+        raise ShapeError("y_true and scores must have the same number of rows")
+    if k < 1:
+        raise ValueError("k must be >= 1")
+
+    if labels is None:
+        labels = np.arange(scores.shape[1], dtype=y_true.dtype)
+    else:
+        labels = np.array(labels, dtype=y_true.dtype)
+    if len(labels) != scores.shape[1]:
+        raise ValueError("labels must have one entry per score column")
+
+    indices = _label_indices(y_true, labels)
+    x = _top_k_reqs(indices, scores, k)
+    return x[:, 0], x[:, 1]
+
+
+def top_k_accuracy(
+    y_true: np.ndarray,
+    scores: np.ndarray,
+    k: int,
+    labels: Optional[Union[List, np.ndarray]] = None,
+) -> Result:
+    """Calculation of top-k accuracy from a matrix of scores
+
+    Args:
+        y_true (np.ndarray): 1D array of true values (must be bool or int types)
+        scores (np.ndarray): 2D (N, C) array of scores (must be float types)
+        k (int): number of highest scores considered correct
+        labels (optional | list or np.ndarray):
+            labels of the score columns (defaults to 0..C-1)
+    Returns:
+        Result: fraction of samples where the true label is in the top-k scores
+    """
+    hits, support = _top_k_from_ext(y_true, scores, k, labels)
+    with np.errstate(divide="ignore", invalid="ignore"):
+        return (hits.sum() / support.sum()).item()
+
+
+def top_k_recall(
+    y_true: np.ndarray,
+    scores: np.ndarray,
+    k: int,
+    labels: Optional[Union[List, np.ndarray]] = None,
+    zero_division: ZeroDivision = ZeroDivision.NONE,
+) -> Result:
+    """Per-class calculation of top-k recall from a matrix of scores
+
+    Args:
+        y_true (np.ndarray): 1D array of true values (must be bool or int types)
+        scores (np.ndarray): 2D (N, C) array of scores (must be float types)
+        k (int): number of highest scores considered correct
+        labels (optional | list or np.ndarray):
+            labels of the score columns (defaults to 0..C-1)
+        zero_division (optional | str): strategy to handle division by 0
+    Returns:
+        top-k recall (np.ndarray): 1D array of top-k recall per label
+    """
+    zero_division = ZeroDivision(zero_division)
+    hits, support = _top_k_from_ext(y_true, scores, k, labels)
+    zero_handle = _get_zero_handler(zero_division)
+    with np.errstate(divide="ignore", invalid="ignore"):
+        return zero_handle(hits / support)
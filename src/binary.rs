@@ -1,7 +1,8 @@
 use numpy::*;
 use pyo3::prelude::*;
 
-use crate::numpy_dispatch_no_bool;
+use crate::utils::values_per_group;
+use crate::{numpy_dispatch_bool, numpy_dispatch_no_bool};
 
 /// Binary precision computational requirements
 #[pyfunction]
@@ -78,6 +79,56 @@ pub fn py_binary_f1_score_reqs<'a>(
     }
 }
 
+/// Binary f1 computational requirements per sample (leading axis)
+#[pyfunction]
+#[pyo3(name = "_binary_f1_score_reqs_batch")]
+#[pyo3(text_signature = "(actual: np.ndarray, pred: np.ndarray, /)")]
+pub fn py_binary_f1_score_reqs_batch<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    pred: &'a PyAny,
+) -> PyResult<&'a PyArray2<i64>> {
+    if let (Ok(i), Ok(j)) = (
+        actual.extract::<PyReadonlyArrayDyn<bool>>(),
+        pred.extract::<PyReadonlyArrayDyn<bool>>(),
+    ) {
+        binary_f1_score_reqs_batch_bool(py, i, j)
+    } else {
+        numpy_dispatch_no_bool!(
+            py,
+            binary_f1_score_reqs_batch,
+            PyResult<&'a PyArray2<i64>>,
+            actual,
+            pred
+        )
+    }
+}
+
+/// Binary f1 computational requirements per group
+#[pyfunction]
+#[pyo3(name = "_binary_f1_score_reqs_grouped")]
+#[pyo3(
+    text_signature = "(actual: np.ndarray, pred: np.ndarray, groups: np.ndarray, n_groups: int, /)"
+)]
+pub fn py_binary_f1_score_reqs_grouped<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    pred: &'a PyAny,
+    groups: PyReadonlyArray1<i64>,
+    n_groups: usize,
+) -> PyResult<&'a PyArray2<i64>> {
+    let groups = groups.as_array();
+    numpy_dispatch_bool!(
+        py,
+        binary_f1_score_reqs_grouped,
+        PyResult<&'a PyArray2<i64>>,
+        actual,
+        pred;
+        groups,
+        n_groups
+    )
+}
+
 fn binary_precision_reqs<'a, T>(
     _py: Python<'a>,
     actual: numpy::PyReadonlyArrayDyn<T>,
@@ -99,8 +150,8 @@ where
                 acc.1 + elt.0.clone().into(),
             )
         });
-        reqs.0 = reqs.0 + row_reqs.0;
-        reqs.1 = reqs.1 + row_reqs.1;
+        reqs.0 += row_reqs.0;
+        reqs.1 += row_reqs.1;
     }
     Ok(reqs)
 }
@@ -113,13 +164,10 @@ fn binary_precision_reqs_bool<'a>(
     let mut reqs = (0, 0, 0);
     for (r1, r2) in std::iter::zip(pred.as_array().rows(), actual.as_array().rows()) {
         let row_reqs = std::iter::zip(r1, r2).fold((0, 0), |acc, elt| {
-            (
-                acc.0 + (elt.0.clone() & elt.1.clone()) as i128,
-                acc.1 + (elt.0.clone()) as i128,
-            )
+            (acc.0 + (*elt.0 & *elt.1) as i128, acc.1 + (*elt.0) as i128)
         });
-        reqs.0 = reqs.0 + row_reqs.0;
-        reqs.1 = reqs.1 + row_reqs.1;
+        reqs.0 += row_reqs.0;
+        reqs.1 += row_reqs.1;
     }
     Ok(reqs)
 }
@@ -145,8 +193,8 @@ where
                 acc.1 + elt.1.clone().into(),
             )
         });
-        reqs.0 = reqs.0 + row_reqs.0;
-        reqs.1 = reqs.1 + row_reqs.1;
+        reqs.0 += row_reqs.0;
+        reqs.1 += row_reqs.1;
     }
     Ok(reqs)
 }
@@ -159,13 +207,10 @@ fn binary_recall_reqs_bool<'a>(
     let mut reqs = (0, 0, 0);
     for (r1, r2) in std::iter::zip(pred.as_array().rows(), actual.as_array().rows()) {
         let row_reqs = std::iter::zip(r1, r2).fold((0, 0), |acc, elt| {
-            (
-                acc.0 + (elt.0.clone() & elt.1.clone()) as i128,
-                acc.1 + (elt.1.clone()) as i128,
-            )
+            (acc.0 + (*elt.0 & *elt.1) as i128, acc.1 + (*elt.1) as i128)
         });
-        reqs.0 = reqs.0 + row_reqs.0;
-        reqs.1 = reqs.1 + row_reqs.1;
+        reqs.0 += row_reqs.0;
+        reqs.1 += row_reqs.1;
     }
     Ok(reqs)
 }
@@ -192,9 +237,9 @@ where
                 acc.2 + elt.1.clone().into(),
             )
         });
-        reqs.0 = reqs.0 + row_reqs.0;
-        reqs.1 = reqs.1 + row_reqs.1;
-        reqs.2 = reqs.2 + row_reqs.2;
+        reqs.0 += row_reqs.0;
+        reqs.1 += row_reqs.1;
+        reqs.2 += row_reqs.2;
     }
     Ok(reqs)
 }
@@ -208,14 +253,113 @@ fn binary_f1_score_reqs_bool<'a>(
     for (r1, r2) in std::iter::zip(pred.as_array().rows(), actual.as_array().rows()) {
         let row_reqs = std::iter::zip(r1, r2).fold((0, 0, 0), |acc, elt| {
             (
-                acc.0 + (elt.0.clone() & elt.1.clone()) as i128,
-                acc.1 + (elt.0.clone()) as i128,
-                acc.2 + (elt.1.clone()) as i128,
+                acc.0 + (*elt.0 & *elt.1) as i128,
+                acc.1 + (*elt.0) as i128,
+                acc.2 + (*elt.1) as i128,
             )
         });
-        reqs.0 = reqs.0 + row_reqs.0;
-        reqs.1 = reqs.1 + row_reqs.1;
-        reqs.2 = reqs.2 + row_reqs.2;
+        reqs.0 += row_reqs.0;
+        reqs.1 += row_reqs.1;
+        reqs.2 += row_reqs.2;
     }
     Ok(reqs)
 }
+
+fn batch_axis_check(ndim: usize) -> PyResult<()> {
+    if ndim == 0 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "arrays must have a leading batch axis",
+        ));
+    }
+    Ok(())
+}
+
+fn binary_f1_score_reqs_batch<'a, T>(
+    py: Python<'a>,
+    actual: numpy::PyReadonlyArrayDyn<T>,
+    pred: numpy::PyReadonlyArrayDyn<T>,
+) -> PyResult<&'a PyArray2<i64>>
+where
+    T: Clone
+        + std::marker::Send
+        + numpy::Element
+        + std::ops::Add<Output = T>
+        + num_traits::Num
+        + Into<i128>,
+{
+    let (pred, actual) = (pred.as_array(), actual.as_array());
+    batch_axis_check(actual.ndim())?;
+    let mut reqs = ndarray::Array2::<i64>::zeros((actual.len_of(ndarray::Axis(0)), 3));
+    for (mut row, (s1, s2)) in std::iter::zip(
+        reqs.rows_mut(),
+        std::iter::zip(pred.outer_iter(), actual.outer_iter()),
+    ) {
+        let sample_reqs = std::iter::zip(s1.iter(), s2.iter()).fold((0, 0, 0), |acc, elt| {
+            (
+                acc.0 + (elt.0.clone() * elt.1.clone()).into(),
+                acc.1 + elt.0.clone().into(),
+                acc.2 + elt.1.clone().into(),
+            )
+        });
+        row[0] = sample_reqs.0 as i64;
+        row[1] = sample_reqs.1 as i64;
+        row[2] = sample_reqs.2 as i64;
+    }
+    Ok(PyArray2::from_array(py, &reqs))
+}
+
+fn binary_f1_score_reqs_batch_bool<'a>(
+    py: Python<'a>,
+    actual: numpy::PyReadonlyArrayDyn<bool>,
+    pred: numpy::PyReadonlyArrayDyn<bool>,
+) -> PyResult<&'a PyArray2<i64>> {
+    let (pred, actual) = (pred.as_array(), actual.as_array());
+    batch_axis_check(actual.ndim())?;
+    let mut reqs = ndarray::Array2::<i64>::zeros((actual.len_of(ndarray::Axis(0)), 3));
+    for (mut row, (s1, s2)) in std::iter::zip(
+        reqs.rows_mut(),
+        std::iter::zip(pred.outer_iter(), actual.outer_iter()),
+    ) {
+        let sample_reqs = std::iter::zip(s1.iter(), s2.iter()).fold((0, 0, 0), |acc, elt| {
+            (
+                acc.0 + (*elt.0 & *elt.1) as i64,
+                acc.1 + (*elt.0) as i64,
+                acc.2 + (*elt.1) as i64,
+            )
+        });
+        row[0] = sample_reqs.0;
+        row[1] = sample_reqs.1;
+        row[2] = sample_reqs.2;
+    }
+    Ok(PyArray2::from_array(py, &reqs))
+}
+
+/// (G, 3) TP, TP + FP and TP + FN of every group in a single pass, `groups` holds
+/// the group index of every entry of the leading axes
+fn binary_f1_score_reqs_grouped<'a, T>(
+    py: Python<'a>,
+    actual: numpy::PyReadonlyArrayDyn<T>,
+    pred: numpy::PyReadonlyArrayDyn<T>,
+    groups: ndarray::ArrayView1<i64>,
+    n_groups: usize,
+) -> PyResult<&'a PyArray2<i64>>
+where
+    T: Copy + numpy::Element + Into<i128>,
+{
+    let (pred, actual) = (pred.as_array(), actual.as_array());
+    if pred.shape() != actual.shape() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "actual and pred must be same shape",
+        ));
+    }
+    let per_group = values_per_group(groups, actual.len(), n_groups)?;
+    let mut reqs = ndarray::Array2::<i64>::zeros((n_groups, 3));
+    for (i, (a, p)) in std::iter::zip(actual.iter(), pred.iter()).enumerate() {
+        let (a, p): (i128, i128) = ((*a).into(), (*p).into());
+        let g = groups[i / per_group] as usize;
+        reqs[[g, 0]] += (a * p) as i64;
+        reqs[[g, 1]] += p as i64;
+        reqs[[g, 2]] += a as i64;
+    }
+    Ok(PyArray2::from_array(py, &reqs))
+}
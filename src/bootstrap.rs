@@ -0,0 +1,303 @@
+use ndarray::{Array2, ArrayView1, ArrayView2};
+use numpy::*;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+use crate::special::{norm_cdf, norm_ppf};
+
+/// Summed counts of the resampled units of every bootstrap replicate
+#[pyfunction]
+#[pyo3(name = "_bootstrap_totals")]
+#[pyo3(
+    text_signature = "(counts: np.ndarray, multiplicity: np.ndarray, n_boot: int, seed: int, /)"
+)]
+pub fn py_bootstrap_totals<'a>(
+    py: Python<'a>,
+    counts: PyReadonlyArray2<i64>,
+    multiplicity: PyReadonlyArray1<i64>,
+    n_boot: usize,
+    seed: u64,
+) -> PyResult<&'a PyArray2<i64>> {
+    let (counts, multiplicity) = (counts.as_array(), multiplicity.as_array());
+    if counts.nrows() != multiplicity.len() || multiplicity.iter().any(|m| *m < 0) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "multiplicity must be one non-negative count per row of counts",
+        ));
+    }
+    let x = _bootstrap_totals(counts, multiplicity, n_boot, seed);
+    Ok(PyArray2::from_array(py, &x))
+}
+
+/// Percentile or BCa confidence interval from bootstrap replicates
+#[pyfunction]
+#[pyo3(name = "_bootstrap_interval")]
+#[pyo3(
+    text_signature = "(replicates: np.ndarray, jackknife: Optional[np.ndarray], estimate: float, confidence: float, /)"
+)]
+pub fn py_bootstrap_interval(
+    replicates: PyReadonlyArray1<f64>,
+    jackknife: Option<PyReadonlyArray2<f64>>,
+    estimate: f64,
+    confidence: f64,
+) -> PyResult<(f64, f64)> {
+    if !(0.0..1.0).contains(&confidence) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "confidence must be in [0, 1)",
+        ));
+    }
+    let jackknife: Option<Vec<(f64, f64)>> = match jackknife {
+        Some(j) if j.shape()[1] != 2 => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "jackknife must have shape (K, 2)",
+            ))
+        }
+        Some(j) => Some(
+            j.as_array()
+                .rows()
+                .into_iter()
+                .map(|r| (r[0], r[1]))
+                .collect(),
+        ),
+        None => None,
+    };
+    Ok(_bootstrap_interval(
+        &replicates.to_vec()?,
+        jackknife.as_deref(),
+        estimate,
+        confidence,
+    ))
+}
+
+/// splitmix64 step, used to seed the generator
+fn splitmix(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// xoshiro256** generator, every bootstrap replicate gets its own stream so the
+/// results do not depend on the number of threads
+pub struct Rng {
+    s: [u64; 4],
+}
+
+impl Rng {
+    pub fn new(seed: u64, stream: u64) -> Rng {
+        let mut x = seed ^ splitmix(stream);
+        let mut s = [0; 4];
+        for v in s.iter_mut() {
+            x = splitmix(x);
+            *v = x;
+        }
+        Rng { s }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.s[1] << 17;
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+        result
+    }
+
+    /// Uniform in [0, 1)
+    pub fn uniform(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform integer in [0, n) without modulo bias (Lemire)
+    pub fn below(&mut self, n: u64) -> u64 {
+        let mut m = self.next_u64() as u128 * n as u128;
+        if (m as u64) < n {
+            let threshold = n.wrapping_neg() % n;
+            while (m as u64) < threshold {
+                m = self.next_u64() as u128 * n as u128;
+            }
+        }
+        (m >> 64) as u64
+    }
+
+    fn normal(&mut self) -> f64 {
+        let (u1, u2) = (1.0 - self.uniform(), self.uniform());
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+
+    /// Gamma(shape, 1) for shape >= 1 (Marsaglia and Tsang)
+    fn gamma(&mut self, shape: f64) -> f64 {
+        let d = shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * d).sqrt();
+        loop {
+            let x = self.normal();
+            let v = (1.0 + c * x).powi(3);
+            if v <= 0.0 {
+                continue;
+            }
+            let u = self.uniform();
+            if u < 1.0 - 0.0331 * x.powi(4) || u.ln() < 0.5 * x * x + d * (1.0 - v + v.ln()) {
+                return d * v;
+            }
+        }
+    }
+
+    /// Binomial(n, p) by Knuth's recursion on the beta distributed order
+    /// statistics of the uniforms, exact and O(log n)
+    pub fn binomial(&mut self, mut n: u64, mut p: f64) -> u64 {
+        let mut successes = 0;
+        while n > 64 {
+            if p <= 0.0 {
+                return successes;
+            }
+            if p >= 1.0 {
+                return successes + n;
+            }
+            let i = 1 + n / 2;
+            let a = self.gamma(i as f64);
+            let x = a / (a + self.gamma((n + 1 - i) as f64));
+            if x >= p {
+                n = i - 1;
+                p /= x;
+            } else {
+                successes += i;
+                n -= i;
+                p = (p - x) / (1.0 - x);
+            }
+        }
+        successes + (0..n).filter(|_| self.uniform() < p).count() as u64
+    }
+}
+
+/// Number of draws of every unit type when `n` units are resampled from types
+/// with the given multiplicities
+fn multinomial(rng: &mut Rng, multiplicity: ArrayView1<i64>, n: u64) -> Vec<u64> {
+    let mut remaining_weight: i64 = multiplicity.sum();
+    let mut remaining = n;
+    multiplicity
+        .iter()
+        .map(|m| {
+            if remaining == 0 || *m == 0 {
+                return 0;
+            }
+            let drawn = if *m >= remaining_weight {
+                remaining
+            } else {
+                rng.binomial(remaining, *m as f64 / remaining_weight as f64)
+            };
+            remaining -= drawn;
+            remaining_weight -= m;
+            drawn
+        })
+        .collect()
+}
+
+/// (B, M) counts summed over the units of every replicate, where unit types are
+/// rows of `counts` appearing `multiplicity` times. Few repeated types are drawn
+/// as a multinomial so the cost does not grow with the number of units.
+pub fn _bootstrap_totals(
+    counts: ArrayView2<i64>,
+    multiplicity: ArrayView1<i64>,
+    n_boot: usize,
+    seed: u64,
+) -> Array2<i64> {
+    let n_units: i64 = multiplicity.sum();
+    let cumulative: Vec<i64> = multiplicity
+        .iter()
+        .scan(0, |acc, m| {
+            *acc += m;
+            Some(*acc)
+        })
+        .collect();
+    let draw_units = n_units as usize <= 16 * multiplicity.len();
+
+    let totals: Vec<Vec<i64>> = (0..n_boot)
+        .into_par_iter()
+        .map(|r| {
+            let mut rng = Rng::new(seed, r as u64);
+            let mut draws = vec![0; multiplicity.len()];
+            if draw_units {
+                for _ in 0..n_units {
+                    let u = rng.below(n_units as u64) as i64;
+                    draws[cumulative.partition_point(|c| *c <= u)] += 1;
+                }
+            } else {
+                draws = multinomial(&mut rng, multiplicity, n_units as u64);
+            }
+            let mut total = vec![0; counts.ncols()];
+            for (row, d) in counts.rows().into_iter().zip(draws.iter()) {
+                if *d > 0 {
+                    for (t, c) in total.iter_mut().zip(row.iter()) {
+                        *t += c * *d as i64;
+                    }
+                }
+            }
+            total
+        })
+        .collect();
+    Array2::from_shape_fn((n_boot, counts.ncols()), |(r, m)| totals[r][m])
+}
+
+/// Linear interpolation between the closest ranks like `np.quantile`
+fn sorted_quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let k = position.floor() as usize;
+    if k + 1 >= sorted.len() {
+        return sorted[sorted.len() - 1];
+    }
+    sorted[k] + (sorted[k + 1] - sorted[k]) * (position - k as f64)
+}
+
+/// BCa acceleration from weighted jackknife (value, weight) pairs
+fn acceleration(jackknife: &[(f64, f64)]) -> f64 {
+    let values: Vec<&(f64, f64)> = jackknife.iter().filter(|j| !j.0.is_nan()).collect();
+    let total: f64 = values.iter().map(|j| j.1).sum();
+    let mean = values.iter().map(|j| j.0 * j.1).sum::<f64>() / total;
+    let (mut num, mut den) = (0.0, 0.0);
+    for (value, weight) in values {
+        let d = mean - value;
+        num += weight * d * d * d;
+        den += weight * d * d;
+    }
+    if den > 0.0 {
+        num / (6.0 * den.powf(1.5))
+    } else {
+        0.0
+    }
+}
+
+/// Equal tailed interval of the replicates (NaN replicates are dropped). With
+/// jackknife values the percentiles are bias corrected and accelerated (BCa),
+/// falling back to plain percentiles when every replicate is on one side of the
+/// estimate.
+pub fn _bootstrap_interval(
+    replicates: &[f64],
+    jackknife: Option<&[(f64, f64)]>,
+    estimate: f64,
+    confidence: f64,
+) -> (f64, f64) {
+    let mut sorted: Vec<f64> = replicates.iter().copied().filter(|r| !r.is_nan()).collect();
+    if sorted.is_empty() || estimate.is_nan() {
+        return (f64::NAN, f64::NAN);
+    }
+    sorted.sort_by(f64::total_cmp);
+
+    let alpha = (1.0 - confidence) / 2.0;
+    let (mut low, mut high) = (alpha, 1.0 - alpha);
+    if let Some(jackknife) = jackknife {
+        let below = sorted.partition_point(|r| *r < estimate) as f64 / sorted.len() as f64;
+        let z0 = norm_ppf(below);
+        let a = acceleration(jackknife);
+        if z0.is_finite() && a.is_finite() {
+            let adjust = |z: f64| norm_cdf(z0 + (z0 + z) / (1.0 - a * (z0 + z)));
+            low = adjust(norm_ppf(alpha));
+            high = adjust(norm_ppf(1.0 - alpha));
+        }
+    }
+    (
+        sorted_quantile(&sorted, low),
+        sorted_quantile(&sorted, high),
+    )
+}
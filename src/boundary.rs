@@ -0,0 +1,234 @@
+use ndarray::{ArrayD, ArrayViewD, Axis, Zip};
+use numpy::*;
+use pyo3::prelude::*;
+
+use crate::numpy_dispatch_bool;
+
+/// Boundary of a binary mask
+#[pyfunction]
+#[pyo3(name = "_boundary")]
+#[pyo3(text_signature = "(mask: np.ndarray, /)")]
+pub fn py_boundary<'a>(py: Python<'a>, mask: &'a PyAny) -> PyResult<&'a PyArrayDyn<bool>> {
+    numpy_dispatch_bool!(py, boundary, PyResult<&'a PyArrayDyn<bool>>, mask)
+}
+
+/// Boundary F1 computational requirements
+#[pyfunction]
+#[pyo3(name = "_boundary_f1_reqs")]
+#[pyo3(text_signature = "(actual: np.ndarray, pred: np.ndarray, tolerance: float, /)")]
+pub fn py_boundary_f1_reqs<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    pred: &'a PyAny,
+    tolerance: f64,
+) -> PyResult<(i64, i64, i64, i64)> {
+    numpy_dispatch_bool!(
+        py,
+        boundary_f1_reqs,
+        PyResult<(i64, i64, i64, i64)>,
+        actual,
+        pred;
+        tolerance
+    )
+}
+
+/// Boundary IoU computational requirements
+#[pyfunction]
+#[pyo3(name = "_boundary_iou_reqs")]
+#[pyo3(text_signature = "(actual: np.ndarray, pred: np.ndarray, dilation: float, /)")]
+pub fn py_boundary_iou_reqs<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    pred: &'a PyAny,
+    dilation: f64,
+) -> PyResult<(i64, i64)> {
+    numpy_dispatch_bool!(
+        py,
+        boundary_iou_reqs,
+        PyResult<(i64, i64)>,
+        actual,
+        pred;
+        dilation
+    )
+}
+
+fn shape_check<T>(actual: &PyReadonlyArrayDyn<T>, pred: &PyReadonlyArrayDyn<T>) -> PyResult<()>
+where
+    T: numpy::Element,
+{
+    if actual.shape() != pred.shape() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "actual and pred must be same shape",
+        ));
+    }
+    Ok(())
+}
+
+fn to_mask<T>(arr: ArrayViewD<T>) -> ArrayD<bool>
+where
+    T: Copy + Into<i128>,
+{
+    arr.mapv(|x| x.into() != 0)
+}
+
+/// Foreground pixels with a background (or out of bounds) neighbour along any
+/// axis, i.e. the 4-connected boundary in 2-D and 6-connected boundary in 3-D
+pub fn _boundary(mask: &ArrayD<bool>) -> ArrayD<bool> {
+    let mut interior = mask.clone();
+    for k in 0..mask.ndim() {
+        let (axis, n) = (Axis(k), mask.len_of(Axis(k)));
+        if n == 0 {
+            return interior;
+        }
+        Zip::from(interior.slice_axis_mut(axis, (1..n).into()))
+            .and(mask.slice_axis(axis, (0..n - 1).into()))
+            .for_each(|x, &y| *x &= y);
+        Zip::from(interior.slice_axis_mut(axis, (0..n - 1).into()))
+            .and(mask.slice_axis(axis, (1..n).into()))
+            .for_each(|x, &y| *x &= y);
+        interior.index_axis_mut(axis, 0).fill(false);
+        interior.index_axis_mut(axis, n - 1).fill(false);
+    }
+    Zip::from(&mut interior)
+        .and(mask)
+        .for_each(|x, &y| *x = y && !*x);
+    interior
+}
+
+/// 1-D squared distance transform of sampled function `f` (Felzenszwalb &
+/// Huttenlocher) where `w` is the squared sample spacing, infinite samples are
+/// never a feature
+fn distance_transform_1d(f: &[f64], w: f64, d: &mut [f64], v: &mut [usize], z: &mut [f64]) {
+    let mut samples = (0..f.len()).filter(|q| f[*q].is_finite());
+    let first = match samples.next() {
+        Some(q) => q,
+        None => {
+            d.fill(f64::INFINITY);
+            return;
+        }
+    };
+    let intersect = |q: usize, p: usize| {
+        let (qf, pf) = (q as f64, p as f64);
+        ((f[q] + w * qf * qf) - (f[p] + w * pf * pf)) / (2.0 * w * (qf - pf))
+    };
+    let mut k = 0;
+    v[0] = first;
+    z[0] = f64::NEG_INFINITY;
+    z[1] = f64::INFINITY;
+    for q in samples {
+        let mut s = intersect(q, v[k]);
+        while s <= z[k] {
+            k -= 1;
+            s = intersect(q, v[k]);
+        }
+        k += 1;
+        v[k] = q;
+        z[k] = s;
+        z[k + 1] = f64::INFINITY;
+    }
+    k = 0;
+    for (q, dq) in d.iter_mut().enumerate() {
+        while z[k + 1] < q as f64 {
+            k += 1;
+        }
+        let diff = q as f64 - v[k] as f64;
+        *dq = w * diff * diff + f[v[k]];
+    }
+}
+
+/// Exact squared Euclidean distance from every element to the nearest `true`
+/// element of `features` with per-axis `spacing` (infinite if there are none)
+pub fn _squared_distance_transform(features: &ArrayD<bool>, spacing: &[f64]) -> ArrayD<f64> {
+    let mut dist = features.mapv(|x| if x { 0.0 } else { f64::INFINITY });
+    for (k, s) in spacing.iter().enumerate().take(features.ndim()) {
+        let n = features.len_of(Axis(k));
+        let (mut f, mut d) = (vec![0.0; n], vec![0.0; n]);
+        let (mut v, mut z) = (vec![0; n], vec![0.0; n + 1]);
+        for mut lane in dist.lanes_mut(Axis(k)) {
+            for (fi, x) in f.iter_mut().zip(lane.iter()) {
+                *fi = *x;
+            }
+            distance_transform_1d(&f, s * s, &mut d, &mut v, &mut z);
+            for (x, di) in lane.iter_mut().zip(d.iter()) {
+                *x = *di;
+            }
+        }
+    }
+    dist
+}
+
+/// Number of `points` within `tolerance` of a `target` element and number of `points`
+fn matched_within(points: &ArrayD<bool>, target: &ArrayD<bool>, tolerance: f64) -> (i64, i64) {
+    let spacing = vec![1.0; target.ndim()];
+    let dist = _squared_distance_transform(target, &spacing);
+    let tol2 = tolerance * tolerance;
+    Zip::from(points).and(&dist).fold((0, 0), |acc, &p, &d| {
+        (acc.0 + (p && d <= tol2) as i64, acc.1 + p as i64)
+    })
+}
+
+/// Foreground elements within `dilation` of the background, where everything
+/// outside of the array counts as background
+fn inner_band(mask: &ArrayD<bool>, dilation: f64) -> ArrayD<bool> {
+    let padded_shape: Vec<usize> = mask.shape().iter().map(|n| n + 2).collect();
+    let mut background = ArrayD::<bool>::from_elem(padded_shape, true);
+    let mut inner = background.view_mut();
+    for k in 0..mask.ndim() {
+        let n = mask.len_of(Axis(k));
+        inner.slice_axis_inplace(Axis(k), (1..n + 1).into());
+    }
+    Zip::from(&mut inner).and(mask).for_each(|x, &y| *x = !y);
+
+    let spacing = vec![1.0; mask.ndim()];
+    let mut dist = _squared_distance_transform(&background, &spacing);
+    for k in 0..mask.ndim() {
+        let n = mask.len_of(Axis(k));
+        dist.slice_axis_inplace(Axis(k), (1..n + 1).into());
+    }
+    let dil2 = dilation * dilation;
+    Zip::from(mask)
+        .and(&dist)
+        .map_collect(|&m, &d| m && d <= dil2)
+}
+
+fn boundary<'a, T>(py: Python<'a>, mask: PyReadonlyArrayDyn<T>) -> PyResult<&'a PyArrayDyn<bool>>
+where
+    T: Copy + numpy::Element + Into<i128>,
+{
+    let mask = to_mask(mask.as_array());
+    Ok(PyArrayDyn::from_array(py, &_boundary(&mask)))
+}
+
+fn boundary_f1_reqs<T>(
+    _py: Python<'_>,
+    actual: PyReadonlyArrayDyn<T>,
+    pred: PyReadonlyArrayDyn<T>,
+    tolerance: f64,
+) -> PyResult<(i64, i64, i64, i64)>
+where
+    T: Copy + numpy::Element + Into<i128>,
+{
+    shape_check(&actual, &pred)?;
+    let actual = _boundary(&to_mask(actual.as_array()));
+    let pred = _boundary(&to_mask(pred.as_array()));
+    let (pred_matched, pred_total) = matched_within(&pred, &actual, tolerance);
+    let (actual_matched, actual_total) = matched_within(&actual, &pred, tolerance);
+    Ok((pred_matched, pred_total, actual_matched, actual_total))
+}
+
+fn boundary_iou_reqs<T>(
+    _py: Python<'_>,
+    actual: PyReadonlyArrayDyn<T>,
+    pred: PyReadonlyArrayDyn<T>,
+    dilation: f64,
+) -> PyResult<(i64, i64)>
+where
+    T: Copy + numpy::Element + Into<i128>,
+{
+    shape_check(&actual, &pred)?;
+    let actual = inner_band(&to_mask(actual.as_array()), dilation);
+    let pred = inner_band(&to_mask(pred.as_array()), dilation);
+    Ok(Zip::from(&actual).and(&pred).fold((0, 0), |acc, &a, &p| {
+        (acc.0 + (a && p) as i64, acc.1 + (a || p) as i64)
+    }))
+}
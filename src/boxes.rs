@@ -0,0 +1,147 @@
+use ndarray::{Array2, ArrayView1, ArrayView2};
+use numpy::*;
+use pyo3::prelude::*;
+
+/// Pairwise IoU (or GIoU, DIoU, CIoU) of xyxy boxes
+#[pyfunction]
+#[pyo3(name = "_box_iou")]
+#[pyo3(text_signature = "(boxes_a: np.ndarray, boxes_b: np.ndarray, kind: str, /)")]
+pub fn py_box_iou<'a>(
+    py: Python<'a>,
+    boxes_a: &'a PyAny,
+    boxes_b: &'a PyAny,
+    kind: &str,
+) -> PyResult<&'a PyArray2<f64>> {
+    let kind = match kind {
+        "iou" => IouKind::Iou,
+        "giou" => IouKind::Generalized,
+        "diou" => IouKind::Distance,
+        "ciou" => IouKind::Complete,
+        _ => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "kind must be one of iou, giou, diou or ciou",
+            ))
+        }
+    };
+    if let (Ok(a), Ok(b)) = (
+        boxes_a.extract::<PyReadonlyArray2<f32>>(),
+        boxes_b.extract::<PyReadonlyArray2<f32>>(),
+    ) {
+        let x = _box_iou(a.as_array(), b.as_array(), kind)?;
+        return Ok(PyArray2::from_array(py, &x));
+    }
+    if let (Ok(a), Ok(b)) = (
+        boxes_a.extract::<PyReadonlyArray2<f64>>(),
+        boxes_b.extract::<PyReadonlyArray2<f64>>(),
+    ) {
+        let x = _box_iou(a.as_array(), b.as_array(), kind)?;
+        return Ok(PyArray2::from_array(py, &x));
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        "Unsupported numpy dtype",
+    ))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum IouKind {
+    Iou,
+    Generalized,
+    Distance,
+    Complete,
+}
+
+/// xyxy box with its width, height and area (degenerate boxes have 0 area)
+pub struct BoxGeom {
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+    w: f64,
+    h: f64,
+    pub area: f64,
+}
+
+impl BoxGeom {
+    pub fn new<F: Copy + Into<f64>>(row: ArrayView1<F>) -> BoxGeom {
+        let (x1, y1, x2, y2) = (row[0].into(), row[1].into(), row[2].into(), row[3].into());
+        let (w, h) = ((x2 - x1).max(0.0), (y2 - y1).max(0.0));
+        BoxGeom {
+            x1,
+            y1,
+            x2,
+            y2,
+            w,
+            h,
+            area: w * h,
+        }
+    }
+
+    pub fn intersection(&self, other: &BoxGeom) -> f64 {
+        let iw = (self.x2.min(other.x2) - self.x1.max(other.x1)).max(0.0);
+        let ih = (self.y2.min(other.y2) - self.y1.max(other.y1)).max(0.0);
+        iw * ih
+    }
+}
+
+/// IoU of a pair of boxes and its penalised variants, the IoU of two boxes with
+/// an empty union is 0
+pub fn pair_iou(a: &BoxGeom, b: &BoxGeom, kind: IouKind) -> f64 {
+    let inter = a.intersection(b);
+    let union = a.area + b.area - inter;
+    let iou = if union > 0.0 { inter / union } else { 0.0 };
+    if kind == IouKind::Iou {
+        return iou;
+    }
+
+    // smallest box enclosing both
+    let cw = a.x2.max(b.x2) - a.x1.min(b.x1);
+    let ch = a.y2.max(b.y2) - a.y1.min(b.y1);
+    if kind == IouKind::Generalized {
+        let enclosing = cw * ch;
+        return if enclosing > 0.0 {
+            iou - (enclosing - union) / enclosing
+        } else {
+            iou
+        };
+    }
+
+    let diagonal = cw * cw + ch * ch;
+    let dx = (a.x1 + a.x2 - b.x1 - b.x2) / 2.0;
+    let dy = (a.y1 + a.y2 - b.y1 - b.y2) / 2.0;
+    let diou = if diagonal > 0.0 {
+        iou - (dx * dx + dy * dy) / diagonal
+    } else {
+        iou
+    };
+    if kind == IouKind::Distance {
+        return diou;
+    }
+
+    // aspect ratio consistency
+    let v = 4.0 / (std::f64::consts::PI * std::f64::consts::PI)
+        * ((b.w / b.h).atan() - (a.w / a.h).atan()).powi(2);
+    let v = if v.is_finite() { v } else { 0.0 };
+    let alpha = if v > 0.0 { v / (1.0 - iou + v) } else { 0.0 };
+    diou - alpha * v
+}
+
+/// (N, M) matrix of pairwise IoUs between (N, 4) and (M, 4) xyxy boxes
+pub fn _box_iou<F>(
+    boxes_a: ArrayView2<F>,
+    boxes_b: ArrayView2<F>,
+    kind: IouKind,
+) -> PyResult<Array2<f64>>
+where
+    F: Copy + Into<f64>,
+{
+    if boxes_a.shape()[1] != 4 || boxes_b.shape()[1] != 4 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "boxes must have shape (N, 4)",
+        ));
+    }
+    let a: Vec<BoxGeom> = boxes_a.rows().into_iter().map(BoxGeom::new).collect();
+    let b: Vec<BoxGeom> = boxes_b.rows().into_iter().map(BoxGeom::new).collect();
+    Ok(Array2::from_shape_fn((a.len(), b.len()), |(i, j)| {
+        pair_iou(&a[i], &b[j], kind)
+    }))
+}
@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 use std::{collections::HashMap, iter::zip};
 
 use crate::numpy_dispatch_bool;
+use crate::utils::values_per_group;
 
 /// Confusion Matrix
 #[pyfunction]
@@ -37,18 +38,25 @@ where
 {
     let labels = labels.to_vec().unwrap();
     let mut cm = ndarray::Array2::<i64>::from_elem((labels.len(), labels.len()), 0);
-    let idx_map: HashMap<T, usize> = HashMap::from_iter(
-        labels
-            .iter()
-            .enumerate()
-            .map(|(x, y)| (y.clone(), x.clone())),
-    );
-    for (y_pred, y_actual) in zip(pred.as_array().iter(), actual.as_array().iter()) {
+    let idx_map: HashMap<T, usize> =
+        HashMap::from_iter(labels.iter().enumerate().map(|(x, y)| (y.clone(), x)));
+    fill_confusion_matrix(cm.view_mut(), &idx_map, actual.as_array(), pred.as_array());
+    cm
+}
+
+fn fill_confusion_matrix<T>(
+    mut cm: ndarray::ArrayViewMut2<i64>,
+    idx_map: &HashMap<T, usize>,
+    actual: ndarray::ArrayViewD<T>,
+    pred: ndarray::ArrayViewD<T>,
+) where
+    T: std::hash::Hash + std::cmp::Eq,
+{
+    for (y_pred, y_actual) in zip(pred.iter(), actual.iter()) {
         if let (Some(ix1), Some(ix2)) = (idx_map.get(y_actual), idx_map.get(y_pred)) {
             *cm.get_mut((*ix1, *ix2)).unwrap() = *cm.get_mut((*ix1, *ix2)).unwrap() + 1;
         }
     }
-    cm
 }
 
 pub fn confusion_matrix<'a, T>(
@@ -65,3 +73,228 @@ where
         &_confusion_matrix(py, actual, pred, labels),
     ))
 }
+
+/// Confusion Matrix per sample (leading axis)
+#[pyfunction]
+#[pyo3(name = "_confusion_matrix_batch")]
+#[pyo3(
+    text_signature = "(actual: np.ndarray, pred: np.ndarray, labels: Union[List, np.ndarray], /)"
+)]
+pub fn py_confusion_matrix_batch<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    pred: &'a PyAny,
+    labels: &'a PyAny,
+) -> PyResult<&'a PyArray3<i64>> {
+    numpy_dispatch_bool!(
+        py,
+        confusion_matrix_batch,
+        PyResult<&'a PyArray3<i64>>,
+        actual,
+        pred,
+        labels
+    )
+}
+
+pub fn _confusion_matrix_batch<T>(
+    actual: PyReadonlyArrayDyn<T>,
+    pred: PyReadonlyArrayDyn<T>,
+    labels: PyReadonlyArrayDyn<T>,
+) -> PyResult<ndarray::Array3<i64>>
+where
+    T: Clone + numpy::Element + std::hash::Hash + std::cmp::Eq,
+{
+    let (actual, pred) = (actual.as_array(), pred.as_array());
+    if actual.ndim() == 0 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "arrays must have a leading batch axis",
+        ));
+    }
+    let labels: Vec<T> = labels.as_array().iter().cloned().collect();
+    let n_samples = actual.len_of(ndarray::Axis(0));
+    let mut cm = ndarray::Array3::<i64>::zeros((n_samples, labels.len(), labels.len()));
+    let idx_map: HashMap<T, usize> =
+        HashMap::from_iter(labels.iter().enumerate().map(|(x, y)| (y.clone(), x)));
+    for (sample_cm, (a, p)) in zip(
+        cm.outer_iter_mut(),
+        zip(actual.outer_iter(), pred.outer_iter()),
+    ) {
+        fill_confusion_matrix(sample_cm, &idx_map, a, p);
+    }
+    Ok(cm)
+}
+
+fn confusion_matrix_batch<'a, T>(
+    py: Python<'a>,
+    actual: PyReadonlyArrayDyn<T>,
+    pred: PyReadonlyArrayDyn<T>,
+    labels: PyReadonlyArrayDyn<T>,
+) -> PyResult<&'a PyArray3<i64>>
+where
+    T: Clone + numpy::Element + std::hash::Hash + std::cmp::Eq,
+{
+    Ok(PyArray3::from_array(
+        py,
+        &_confusion_matrix_batch(actual, pred, labels)?,
+    ))
+}
+
+/// Confusion Matrix per group
+#[pyfunction]
+#[pyo3(name = "_confusion_matrix_grouped")]
+#[pyo3(
+    text_signature = "(actual: np.ndarray, pred: np.ndarray, labels: Union[List, np.ndarray], groups: np.ndarray, n_groups: int, /)"
+)]
+pub fn py_confusion_matrix_grouped<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    pred: &'a PyAny,
+    labels: &'a PyAny,
+    groups: PyReadonlyArray1<i64>,
+    n_groups: usize,
+) -> PyResult<&'a PyArray3<i64>> {
+    let groups = groups.as_array();
+    numpy_dispatch_bool!(
+        py,
+        confusion_matrix_grouped,
+        PyResult<&'a PyArray3<i64>>,
+        actual,
+        pred,
+        labels;
+        groups,
+        n_groups
+    )
+}
+
+/// (G, K, K) confusion matrices of every group in a single pass, `groups` holds
+/// the group index of every entry of the leading axes
+fn confusion_matrix_grouped<'a, T>(
+    py: Python<'a>,
+    actual: PyReadonlyArrayDyn<T>,
+    pred: PyReadonlyArrayDyn<T>,
+    labels: PyReadonlyArrayDyn<T>,
+    groups: ndarray::ArrayView1<i64>,
+    n_groups: usize,
+) -> PyResult<&'a PyArray3<i64>>
+where
+    T: Clone + numpy::Element + std::hash::Hash + std::cmp::Eq,
+{
+    let (actual, pred) = (actual.as_array(), pred.as_array());
+    if actual.shape() != pred.shape() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "actual and pred must be same shape",
+        ));
+    }
+    let per_group = values_per_group(groups, actual.len(), n_groups)?;
+    let labels = labels.to_vec()?;
+    let idx_map: HashMap<T, usize> =
+        HashMap::from_iter(labels.iter().enumerate().map(|(x, y)| (y.clone(), x)));
+    let mut cm = ndarray::Array3::<i64>::zeros((n_groups, labels.len(), labels.len()));
+    for (i, (y_actual, y_pred)) in zip(actual.iter(), pred.iter()).enumerate() {
+        if let (Some(ix1), Some(ix2)) = (idx_map.get(y_actual), idx_map.get(y_pred)) {
+            cm[[groups[i / per_group] as usize, *ix1, *ix2]] += 1;
+        }
+    }
+    Ok(PyArray3::from_array(py, &cm))
+}
+
+/// Confusion Matrix from scores (argmax over the class axis)
+#[pyfunction]
+#[pyo3(name = "_confusion_matrix_scores")]
+#[pyo3(
+    text_signature = "(actual: np.ndarray, labels: Union[List, np.ndarray], scores: np.ndarray, class_axis: int, /)"
+)]
+pub fn py_confusion_matrix_scores<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    labels: &'a PyAny,
+    scores: &'a PyAny,
+    class_axis: usize,
+) -> PyResult<&'a PyArray2<i64>> {
+    numpy_dispatch_bool!(
+        py,
+        confusion_matrix_scores,
+        PyResult<&'a PyArray2<i64>>,
+        actual,
+        labels;
+        scores,
+        class_axis
+    )
+}
+
+/// index of the first maximum score (or first NaN) like `np.argmax`
+fn argmax<F>(lane: ndarray::ArrayView1<F>) -> usize
+where
+    F: Copy + PartialOrd,
+{
+    let mut best = 0;
+    for (idx, val) in lane.iter().enumerate() {
+        if val.partial_cmp(val).is_none() {
+            return idx;
+        }
+        if *val > lane[best] {
+            best = idx;
+        }
+    }
+    best
+}
+
+pub fn _confusion_matrix_scores<T, F>(
+    actual: PyReadonlyArrayDyn<T>,
+    labels: PyReadonlyArrayDyn<T>,
+    scores: PyReadonlyArrayDyn<F>,
+    class_axis: usize,
+) -> PyResult<ndarray::Array2<i64>>
+where
+    T: Clone + numpy::Element + std::hash::Hash + std::cmp::Eq,
+    F: Copy + numpy::Element + PartialOrd,
+{
+    let (actual, scores) = (actual.as_array(), scores.as_array());
+    let labels: Vec<T> = labels.as_array().iter().cloned().collect();
+    if class_axis >= scores.ndim() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "class_axis out of range of scores",
+        ));
+    }
+    let axis = ndarray::Axis(class_axis);
+    let mut shape = scores.shape().to_vec();
+    let n_classes = shape.remove(class_axis);
+    if shape != actual.shape() || n_classes != labels.len() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "scores must be actual's shape with a class axis of len(labels)",
+        ));
+    }
+
+    let mut cm = ndarray::Array2::<i64>::from_elem((labels.len(), labels.len()), 0);
+    let idx_map: HashMap<T, usize> =
+        HashMap::from_iter(labels.iter().enumerate().map(|(x, y)| (y.clone(), x)));
+    for (y_actual, lane) in zip(actual.iter(), scores.lanes(axis)) {
+        if let Some(ix1) = idx_map.get(y_actual) {
+            cm[(*ix1, argmax(lane))] += 1;
+        }
+    }
+    Ok(cm)
+}
+
+fn confusion_matrix_scores<'a, T>(
+    py: Python<'a>,
+    actual: PyReadonlyArrayDyn<T>,
+    labels: PyReadonlyArrayDyn<T>,
+    scores: &'a PyAny,
+    class_axis: usize,
+) -> PyResult<&'a PyArray2<i64>>
+where
+    T: Clone + numpy::Element + std::hash::Hash + std::cmp::Eq,
+{
+    if let Ok(s) = scores.extract::<PyReadonlyArrayDyn<f32>>() {
+        let cm = _confusion_matrix_scores(actual, labels, s, class_axis)?;
+        return Ok(PyArray2::from_array(py, &cm));
+    }
+    if let Ok(s) = scores.extract::<PyReadonlyArrayDyn<f64>>() {
+        let cm = _confusion_matrix_scores(actual, labels, s, class_axis)?;
+        return Ok(PyArray2::from_array(py, &cm));
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        "Unsupported numpy dtype",
+    ))
+}
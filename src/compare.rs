@@ -0,0 +1,158 @@
+use ndarray::{Array3, Array4, ArrayView3};
+use numpy::*;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+use crate::bootstrap::Rng;
+use crate::numpy_dispatch_bool;
+use crate::special::{beta_inc, erfc};
+
+/// (C, C, C) counts of every (true, pred_a, pred_b) label triple
+#[pyfunction]
+#[pyo3(name = "_paired_counts")]
+#[pyo3(
+    text_signature = "(actual: np.ndarray, pred_a: np.ndarray, pred_b: np.ndarray, labels: Union[List, np.ndarray], /)"
+)]
+pub fn py_paired_counts<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    pred_a: &'a PyAny,
+    pred_b: &'a PyAny,
+    labels: &'a PyAny,
+) -> PyResult<&'a PyArray3<i64>> {
+    numpy_dispatch_bool!(
+        py,
+        paired_counts,
+        PyResult<&'a PyArray3<i64>>,
+        actual,
+        pred_a,
+        pred_b,
+        labels
+    )
+}
+
+/// TP, TP + FP and TP + FN of both models for every random swap of their
+/// predictions
+#[pyfunction]
+#[pyo3(name = "_paired_permutation_reqs")]
+#[pyo3(text_signature = "(counts: np.ndarray, n_permutations: int, seed: int, /)")]
+pub fn py_paired_permutation_reqs<'a>(
+    py: Python<'a>,
+    counts: PyReadonlyArray3<i64>,
+    n_permutations: usize,
+    seed: u64,
+) -> PyResult<&'a PyArray4<i64>> {
+    let counts = counts.as_array();
+    let shape = counts.shape();
+    if shape[0] != shape[1] || shape[0] != shape[2] || counts.iter().any(|c| *c < 0) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "counts must be a (C, C, C) array of non-negative counts",
+        ));
+    }
+    let x = _paired_permutation_reqs(counts, n_permutations, seed);
+    Ok(PyArray4::from_array(py, &x))
+}
+
+/// McNemar statistic and two sided p-value from the discordant pairs
+#[pyfunction]
+#[pyo3(name = "_mcnemar")]
+#[pyo3(text_signature = "(n01: int, n10: int, exact: bool, /)")]
+pub fn py_mcnemar(n01: u64, n10: u64, exact: bool) -> (f64, f64) {
+    _mcnemar(n01, n10, exact)
+}
+
+fn paired_counts<'a, T>(
+    py: Python<'a>,
+    actual: PyReadonlyArrayDyn<T>,
+    pred_a: PyReadonlyArrayDyn<T>,
+    pred_b: PyReadonlyArrayDyn<T>,
+    labels: PyReadonlyArrayDyn<T>,
+) -> PyResult<&'a PyArray3<i64>>
+where
+    T: Clone + numpy::Element + std::hash::Hash + std::cmp::Eq,
+{
+    let (actual, pred_a, pred_b) = (actual.as_array(), pred_a.as_array(), pred_b.as_array());
+    if actual.shape() != pred_a.shape() || actual.shape() != pred_b.shape() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "actual, pred_a and pred_b must be same shape",
+        ));
+    }
+    let labels = labels.to_vec()?;
+    let idx_map: HashMap<T, usize> =
+        HashMap::from_iter(labels.iter().enumerate().map(|(x, y)| (y.clone(), x)));
+    let mut counts = Array3::<i64>::zeros((labels.len(), labels.len(), labels.len()));
+    // triples with a value outside the labels are left out like the confusion matrix
+    for ((t, a), b) in actual.iter().zip(pred_a.iter()).zip(pred_b.iter()) {
+        if let (Some(i), Some(j), Some(k)) = (idx_map.get(t), idx_map.get(a), idx_map.get(b)) {
+            counts[[*i, *j, *k]] += 1;
+        }
+    }
+    Ok(PyArray3::from_array(py, &counts))
+}
+
+/// (P, 2, C, 3) requirements of models A and B after swapping the predictions of
+/// every value with probability 1/2, drawn per (true, a, b) cell as a binomial
+pub fn _paired_permutation_reqs(
+    counts: ArrayView3<i64>,
+    n_permutations: usize,
+    seed: u64,
+) -> Array4<i64> {
+    let n_classes = counts.shape()[0];
+    let cells: Vec<((usize, usize, usize), i64)> = counts
+        .indexed_iter()
+        .filter(|(_, c)| **c > 0)
+        .map(|(idx, c)| (idx, *c))
+        .collect();
+
+    let reqs: Vec<Array3<i64>> = (0..n_permutations)
+        .into_par_iter()
+        .map(|p| {
+            let mut rng = Rng::new(seed, p as u64);
+            let mut x = Array3::<i64>::zeros((2, n_classes, 3));
+            for ((t, a, b), n) in cells.iter() {
+                let swapped = rng.binomial(*n as u64, 0.5) as i64;
+                // A predicts a for the kept values and b for the swapped ones
+                for (model, kept, other) in [(0, *a, *b), (1, *b, *a)] {
+                    for (pred, m) in [(kept, n - swapped), (other, swapped)] {
+                        x[[model, pred, 1]] += m;
+                        if pred == *t {
+                            x[[model, pred, 0]] += m;
+                        }
+                    }
+                    x[[model, *t, 2]] += n;
+                }
+            }
+            x
+        })
+        .collect();
+
+    let mut x = Array4::<i64>::zeros((n_permutations, 2, n_classes, 3));
+    for (mut row, r) in x.outer_iter_mut().zip(reqs.iter()) {
+        row.assign(r);
+    }
+    x
+}
+
+/// Chi-squared statistic with continuity correction and its p-value, or the
+/// smaller discordant count and the exact binomial p-value
+pub fn _mcnemar(n01: u64, n10: u64, exact: bool) -> (f64, f64) {
+    let n = n01 + n10;
+    if n == 0 {
+        return (0.0, 1.0);
+    }
+    if exact {
+        // P(X <= k) of a Binomial(n, 1/2) is I_{1/2}(n - k, k + 1)
+        let k = n01.min(n10);
+        let cdf = if k == n {
+            1.0
+        } else {
+            beta_inc((n - k) as f64, (k + 1) as f64, 0.5)
+        };
+        return (k as f64, (2.0 * cdf).min(1.0));
+    }
+    let diff = (n01 as f64 - n10 as f64).abs() - 1.0;
+    let statistic = diff.max(0.0).powi(2) / n as f64;
+    // survival function of the chi-squared distribution with 1 degree of freedom
+    (statistic, erfc((statistic / 2.0).sqrt()))
+}
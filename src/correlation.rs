@@ -0,0 +1,181 @@
+use ndarray::{Array1, ArrayView1, ArrayView2};
+use numpy::*;
+use pyo3::prelude::*;
+use std::iter::zip;
+
+/// Pearson, Spearman or Kendall correlation of every pair of columns
+#[pyfunction]
+#[pyo3(name = "_correlation")]
+#[pyo3(text_signature = "(x: np.ndarray, y: np.ndarray, method: str, /)")]
+pub fn py_correlation<'a>(
+    py: Python<'a>,
+    x: &'a PyAny,
+    y: &'a PyAny,
+    method: &str,
+) -> PyResult<&'a PyArray1<f64>> {
+    let method = match method {
+        "pearson" => Correlation::Pearson,
+        "spearman" => Correlation::Spearman,
+        "kendall" => Correlation::Kendall,
+        _ => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "method must be one of pearson, spearman or kendall",
+            ))
+        }
+    };
+    if let (Ok(a), Ok(b)) = (
+        x.extract::<PyReadonlyArray2<f32>>(),
+        y.extract::<PyReadonlyArray2<f32>>(),
+    ) {
+        let r = _correlation(a.as_array(), b.as_array(), method)?;
+        return Ok(PyArray1::from_array(py, &r));
+    }
+    if let (Ok(a), Ok(b)) = (
+        x.extract::<PyReadonlyArray2<f64>>(),
+        y.extract::<PyReadonlyArray2<f64>>(),
+    ) {
+        let r = _correlation(a.as_array(), b.as_array(), method)?;
+        return Ok(PyArray1::from_array(py, &r));
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        "Unsupported numpy dtype",
+    ))
+}
+
+#[derive(Clone, Copy)]
+pub enum Correlation {
+    Pearson,
+    Spearman,
+    Kendall,
+}
+
+/// Pearson correlation from the centred sums, NaN for constant input
+fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len() as f64;
+    let x_mean = x.iter().sum::<f64>() / n;
+    let y_mean = y.iter().sum::<f64>() / n;
+    let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+    for (a, b) in zip(x.iter(), y.iter()) {
+        let (dx, dy) = (a - x_mean, b - y_mean);
+        sxy += dx * dy;
+        sxx += dx * dx;
+        syy += dy * dy;
+    }
+    // rounding can push |r| slightly above 1
+    (sxy / (sxx.sqrt() * syy.sqrt())).clamp(-1.0, 1.0)
+}
+
+/// 1-based ranks where ties get the average of the ranks they span
+pub fn average_ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        let rank = (start + end + 1) as f64 / 2.0;
+        for i in order[start..end].iter() {
+            ranks[*i] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// Number of tied pairs within runs of equal values of a sorted sequence
+fn tied_pairs<T: PartialEq>(sorted: &[T]) -> f64 {
+    let (mut pairs, mut run) = (0.0, 1.0);
+    for i in 1..sorted.len() {
+        if sorted[i] == sorted[i - 1] {
+            run += 1.0;
+        } else {
+            pairs += run * (run - 1.0) / 2.0;
+            run = 1.0;
+        }
+    }
+    pairs + run * (run - 1.0) / 2.0
+}
+
+/// Merge sort of `values` returning the number of swaps needed, which is the
+/// number of pairs out of order
+fn sort_counting_swaps(values: &mut [f64], buffer: &mut [f64]) -> f64 {
+    let n = values.len();
+    if n < 2 {
+        return 0.0;
+    }
+    let mid = n / 2;
+    let mut swaps = sort_counting_swaps(&mut values[..mid], &mut buffer[..mid])
+        + sort_counting_swaps(&mut values[mid..], &mut buffer[mid..]);
+    let (mut i, mut j) = (0, mid);
+    for slot in buffer[..n].iter_mut() {
+        if j == n || (i < mid && values[i] <= values[j]) {
+            *slot = values[i];
+            i += 1;
+        } else {
+            *slot = values[j];
+            swaps += (mid - i) as f64;
+            j += 1;
+        }
+    }
+    values.copy_from_slice(&buffer[..n]);
+    swaps
+}
+
+/// Kendall tau-b in O(n log n) with Knight's algorithm: pairs sorted by x (then
+/// y) are merge sorted by y, every swap being a discordant pair
+fn kendall(x: &[f64], y: &[f64]) -> f64 {
+    let mut pairs: Vec<(f64, f64)> = zip(x.iter().copied(), y.iter().copied()).collect();
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    let x_ties = tied_pairs(&pairs.iter().map(|p| p.0).collect::<Vec<f64>>());
+    let joint_ties = tied_pairs(&pairs);
+
+    let mut sorted_y: Vec<f64> = pairs.iter().map(|p| p.1).collect();
+    let mut buffer = vec![0.0; sorted_y.len()];
+    let swaps = sort_counting_swaps(&mut sorted_y, &mut buffer);
+    let y_ties = tied_pairs(&sorted_y);
+
+    let n = x.len() as f64;
+    let total = n * (n - 1.0) / 2.0;
+    let concordant_minus_discordant = total - x_ties - y_ties + joint_ties - 2.0 * swaps;
+    let tau = concordant_minus_discordant / ((total - x_ties) * (total - y_ties)).sqrt();
+    tau.clamp(-1.0, 1.0)
+}
+
+fn column_correlation<F>(x: ArrayView1<F>, y: ArrayView1<F>, method: Correlation) -> f64
+where
+    F: Copy + Into<f64>,
+{
+    let x: Vec<f64> = x.iter().map(|v| (*v).into()).collect();
+    let y: Vec<f64> = y.iter().map(|v| (*v).into()).collect();
+    if x.iter().chain(y.iter()).any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+    match method {
+        Correlation::Pearson => pearson(&x, &y),
+        Correlation::Spearman => pearson(&average_ranks(&x), &average_ranks(&y)),
+        Correlation::Kendall => kendall(&x, &y),
+    }
+}
+
+/// Correlation of each column of `x` with the same column of `y`, NaN where a
+/// column has NaNs or is constant
+pub fn _correlation<F>(
+    x: ArrayView2<F>,
+    y: ArrayView2<F>,
+    method: Correlation,
+) -> PyResult<Array1<f64>>
+where
+    F: Copy + Into<f64>,
+{
+    if x.shape() != y.shape() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "x and y must be same shape",
+        ));
+    }
+    Ok(zip(x.columns(), y.columns())
+        .map(|(a, b)| column_correlation(a, b, method))
+        .collect())
+}
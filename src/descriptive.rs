@@ -0,0 +1,201 @@
+use ndarray::{Array1, ArrayD, ArrayViewD, Axis, IxDyn};
+use numpy::*;
+use pyo3::prelude::*;
+use std::iter::zip;
+
+/// Number of values in a moments state: count, mean, the 2nd, 3rd and 4th
+/// central moment sums, min and max
+const STATE_LEN: usize = 7;
+
+/// Moments state of an array, optionally per lane along `axis`
+#[pyfunction]
+#[pyo3(name = "_moments")]
+#[pyo3(text_signature = "(arr: np.ndarray, axis: Optional[int], /)")]
+pub fn py_moments<'a>(
+    py: Python<'a>,
+    arr: &'a PyAny,
+    axis: Option<usize>,
+) -> PyResult<&'a PyArrayDyn<f64>> {
+    if let Ok(a) = arr.extract::<PyReadonlyArrayDyn<f32>>() {
+        let x = _moments(a.as_array(), axis)?;
+        return Ok(PyArrayDyn::from_array(py, &x));
+    }
+    if let Ok(a) = arr.extract::<PyReadonlyArrayDyn<f64>>() {
+        let x = _moments(a.as_array(), axis)?;
+        return Ok(PyArrayDyn::from_array(py, &x));
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        "Unsupported numpy dtype",
+    ))
+}
+
+/// Combination of two moments states of the same shape
+#[pyfunction]
+#[pyo3(name = "_merge_moments")]
+#[pyo3(text_signature = "(a: np.ndarray, b: np.ndarray, /)")]
+pub fn py_merge_moments<'a>(
+    py: Python<'a>,
+    a: PyReadonlyArrayDyn<f64>,
+    b: PyReadonlyArrayDyn<f64>,
+) -> PyResult<&'a PyArrayDyn<f64>> {
+    let (a, b) = (a.as_array(), b.as_array());
+    if a.shape() != b.shape() || a.shape().last() != Some(&STATE_LEN) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "moments states must have the same shape (..., 7)",
+        ));
+    }
+    let mut merged = a.to_owned();
+    for (mut m, b) in zip(
+        merged.lanes_mut(Axis(a.ndim() - 1)),
+        b.lanes(Axis(a.ndim() - 1)),
+    ) {
+        let x = Moments::from_slice(&m.to_vec()).merge(&Moments::from_slice(&b.to_vec()));
+        for (v, s) in zip(m.iter_mut(), x.to_state()) {
+            *v = s;
+        }
+    }
+    Ok(PyArrayDyn::from_array(py, &merged))
+}
+
+/// `f64::min` that propagates NaN like `np.min`, so min and max turn NaN together
+/// with the moments
+fn nan_min(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        f64::NAN
+    } else {
+        a.min(b)
+    }
+}
+
+/// `f64::max` that propagates NaN like `np.max`
+fn nan_max(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        f64::NAN
+    } else {
+        a.max(b)
+    }
+}
+
+/// Running count, mean and central moment sums (Welford / Terriberry) with the
+/// pairwise combination of Chan et al. and Pébay for merging states
+#[derive(Clone, Copy)]
+pub struct Moments {
+    n: f64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for Moments {
+    fn default() -> Moments {
+        Moments {
+            n: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            m4: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl Moments {
+    pub fn from_slice(x: &[f64]) -> Moments {
+        Moments {
+            n: x[0],
+            mean: x[1],
+            m2: x[2],
+            m3: x[3],
+            m4: x[4],
+            min: x[5],
+            max: x[6],
+        }
+    }
+
+    pub fn to_state(self) -> [f64; STATE_LEN] {
+        [
+            self.n, self.mean, self.m2, self.m3, self.m4, self.min, self.max,
+        ]
+    }
+
+    pub fn push(&mut self, x: f64) {
+        let n1 = self.n;
+        self.n += 1.0;
+        let n = self.n;
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * n1;
+        self.mean += delta_n;
+        self.m4 += term * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term;
+        self.min = nan_min(self.min, x);
+        self.max = nan_max(self.max, x);
+    }
+
+    pub fn merge(&self, other: &Moments) -> Moments {
+        if other.n == 0.0 {
+            return *self;
+        }
+        if self.n == 0.0 {
+            return *other;
+        }
+        let (na, nb) = (self.n, other.n);
+        let n = na + nb;
+        let delta = other.mean - self.mean;
+        let delta2 = delta * delta;
+        Moments {
+            n,
+            mean: self.mean + delta * nb / n,
+            m2: self.m2 + other.m2 + delta2 * na * nb / n,
+            m3: self.m3
+                + other.m3
+                + delta2 * delta * na * nb * (na - nb) / (n * n)
+                + 3.0 * delta * (na * other.m2 - nb * self.m2) / n,
+            m4: self.m4
+                + other.m4
+                + delta2 * delta2 * na * nb * (na * na - na * nb + nb * nb) / (n * n * n)
+                + 6.0 * delta2 * (na * na * other.m2 + nb * nb * self.m2) / (n * n)
+                + 4.0 * delta * (na * other.m3 - nb * self.m3) / n,
+            min: nan_min(self.min, other.min),
+            max: nan_max(self.max, other.max),
+        }
+    }
+}
+
+/// Moments state (7,) of the whole array, or (..., 7) with `axis` reduced
+pub fn _moments<F>(arr: ArrayViewD<F>, axis: Option<usize>) -> PyResult<ArrayD<f64>>
+where
+    F: Copy + Into<f64>,
+{
+    let axis = match axis {
+        None => {
+            let mut m = Moments::default();
+            arr.iter().for_each(|x| m.push((*x).into()));
+            return Ok(Array1::from_vec(m.to_state().to_vec()).into_dyn());
+        }
+        Some(a) if a >= arr.ndim() => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "axis is out of bounds for the array",
+            ))
+        }
+        Some(a) => a,
+    };
+
+    let mut shape = arr.shape().to_vec();
+    shape.remove(axis);
+    shape.push(STATE_LEN);
+    let mut state = Vec::with_capacity(shape.iter().product());
+    for lane in arr.lanes(Axis(axis)) {
+        let mut m = Moments::default();
+        lane.iter().for_each(|x| m.push((*x).into()));
+        state.extend(m.to_state());
+    }
+    Ok(ArrayD::from_shape_vec(IxDyn(&shape), state).unwrap())
+}
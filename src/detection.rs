@@ -0,0 +1,290 @@
+use ndarray::{ArrayD, IxDyn};
+use numpy::*;
+use pyo3::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+
+use crate::boxes::BoxGeom;
+
+/// COCO-style detection evaluation
+#[pyfunction]
+#[pyo3(name = "_detection_eval")]
+#[pyo3(
+    text_signature = "(gt_boxes: np.ndarray, gt_keys: np.ndarray, gt_areas: np.ndarray, gt_crowd: np.ndarray, dt_boxes: np.ndarray, dt_keys: np.ndarray, dt_scores: np.ndarray, iou_thresholds: List[float], area_ranges: List[Tuple[float, float]], max_dets: List[int], /)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn py_detection_eval<'a>(
+    py: Python<'a>,
+    gt_boxes: PyReadonlyArray2<f64>,
+    gt_keys: PyReadonlyArray2<i64>,
+    gt_areas: PyReadonlyArray1<f64>,
+    gt_crowd: PyReadonlyArray1<bool>,
+    dt_boxes: PyReadonlyArray2<f64>,
+    dt_keys: PyReadonlyArray2<i64>,
+    dt_scores: PyReadonlyArray1<f64>,
+    iou_thresholds: Vec<f64>,
+    area_ranges: Vec<(f64, f64)>,
+    max_dets: Vec<usize>,
+) -> PyResult<(&'a PyArrayDyn<f64>, &'a PyArrayDyn<f64>)> {
+    let n_gt = gt_boxes.shape()[0];
+    let n_dt = dt_boxes.shape()[0];
+    if gt_boxes.shape()[1] != 4
+        || gt_keys.shape() != [n_gt, 2]
+        || gt_areas.len() != n_gt
+        || gt_crowd.len() != n_gt
+        || dt_boxes.shape()[1] != 4
+        || dt_keys.shape() != [n_dt, 2]
+        || dt_scores.len() != n_dt
+    {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "boxes must have shape (N, 4), keys (N, 2) and everything else (N,)",
+        ));
+    }
+
+    let ground: Vec<Ground> = gt_boxes
+        .as_array()
+        .rows()
+        .into_iter()
+        .zip(gt_areas.as_array().iter())
+        .zip(gt_crowd.as_array().iter())
+        .map(|((row, area), crowd)| Ground {
+            geom: BoxGeom::new(row),
+            area: *area,
+            crowd: *crowd,
+        })
+        .collect();
+    let dets: Vec<Detection> = dt_boxes
+        .as_array()
+        .rows()
+        .into_iter()
+        .zip(dt_scores.as_array().iter())
+        .map(|(row, score)| Detection {
+            geom: BoxGeom::new(row),
+            score: *score,
+        })
+        .collect();
+    let gt_keys: Vec<(i64, i64)> = gt_keys
+        .as_array()
+        .rows()
+        .into_iter()
+        .map(|r| (r[0], r[1]))
+        .collect();
+    let dt_keys: Vec<(i64, i64)> = dt_keys
+        .as_array()
+        .rows()
+        .into_iter()
+        .map(|r| (r[0], r[1]))
+        .collect();
+
+    let (precision, recall) = _detection_eval(
+        &ground,
+        &gt_keys,
+        &dets,
+        &dt_keys,
+        &iou_thresholds,
+        &area_ranges,
+        &max_dets,
+    );
+    Ok((
+        PyArrayDyn::from_array(py, &precision),
+        PyArrayDyn::from_array(py, &recall),
+    ))
+}
+
+pub struct Ground {
+    geom: BoxGeom,
+    area: f64,
+    crowd: bool,
+}
+
+pub struct Detection {
+    geom: BoxGeom,
+    score: f64,
+}
+
+/// Matching of the detections of one image and class, detections are in order
+/// of descending score and the flags are per IoU threshold
+struct ImageEval {
+    scores: Vec<f64>,
+    matched: Vec<Vec<bool>>,
+    ignored: Vec<Vec<bool>>,
+    n_gt: usize,
+}
+
+/// Greedy matching of detections to ground truth boxes as done by COCOeval: every
+/// detection takes the unmatched box of highest IoU, preferring boxes that are not
+/// ignored. Crowd boxes can be matched repeatedly and their IoU is relative to the
+/// detection area only.
+fn evaluate_image(
+    gts: &[usize],
+    dts: &[usize],
+    ground: &[Ground],
+    dets: &[Detection],
+    iou_thresholds: &[f64],
+    area_range: (f64, f64),
+    max_det: usize,
+) -> Option<ImageEval> {
+    if gts.is_empty() && dts.is_empty() {
+        return None;
+    }
+    let out_of_range = |area: f64| area < area_range.0 || area > area_range.1;
+    let is_ignored = |g: &usize| ground[*g].crowd || out_of_range(ground[*g].area);
+
+    let mut gts = gts.to_vec();
+    gts.sort_by_key(is_ignored);
+    let gt_ignore: Vec<bool> = gts.iter().map(is_ignored).collect();
+    let mut dts = dts.to_vec();
+    dts.sort_by(|x, y| dets[*y].score.total_cmp(&dets[*x].score));
+    dts.truncate(max_det);
+
+    let ious: Vec<Vec<f64>> = dts
+        .iter()
+        .map(|d| {
+            gts.iter()
+                .map(|g| {
+                    let (dt, gt) = (&dets[*d].geom, &ground[*g]);
+                    let inter = dt.intersection(&gt.geom);
+                    let union = if gt.crowd {
+                        dt.area
+                    } else {
+                        dt.area + gt.geom.area - inter
+                    };
+                    if union > 0.0 {
+                        inter / union
+                    } else {
+                        0.0
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut matched = vec![vec![false; dts.len()]; iou_thresholds.len()];
+    let mut ignored = vec![vec![false; dts.len()]; iou_thresholds.len()];
+    for (t, threshold) in iou_thresholds.iter().enumerate() {
+        let mut gt_matched = vec![false; gts.len()];
+        for (d, row) in ious.iter().enumerate() {
+            let mut best = threshold.min(1.0 - 1e-10);
+            let mut m: Option<usize> = None;
+            for (g, iou) in row.iter().enumerate() {
+                if gt_matched[g] && !ground[gts[g]].crowd {
+                    continue;
+                }
+                // ignored boxes come last, so stop once a regular box matched
+                if matches!(m, Some(prev) if !gt_ignore[prev] && gt_ignore[g]) {
+                    break;
+                }
+                if *iou < best {
+                    continue;
+                }
+                best = *iou;
+                m = Some(g);
+            }
+            match m {
+                Some(g) => {
+                    matched[t][d] = true;
+                    ignored[t][d] = gt_ignore[g];
+                    gt_matched[g] = true;
+                }
+                None => ignored[t][d] = out_of_range(dets[dts[d]].geom.area),
+            }
+        }
+    }
+
+    Some(ImageEval {
+        scores: dts.iter().map(|d| dets[*d].score).collect(),
+        matched,
+        ignored,
+        n_gt: gt_ignore.iter().filter(|x| !**x).count(),
+    })
+}
+
+/// Precision (T, R, K, A, M) at the 101 recall thresholds and recall (T, K, A, M)
+/// over IoU thresholds, classes, area ranges and max detections, -1 where a
+/// class has no ground truth boxes (like COCOeval.accumulate)
+pub fn _detection_eval(
+    ground: &[Ground],
+    gt_keys: &[(i64, i64)],
+    dets: &[Detection],
+    dt_keys: &[(i64, i64)],
+    iou_thresholds: &[f64],
+    area_ranges: &[(f64, f64)],
+    max_dets: &[usize],
+) -> (ArrayD<f64>, ArrayD<f64>) {
+    // (image, class) -> (ground truth, detections)
+    let mut groups: HashMap<(i64, i64), (Vec<usize>, Vec<usize>)> = HashMap::new();
+    for (g, key) in gt_keys.iter().enumerate() {
+        groups.entry(*key).or_default().0.push(g);
+    }
+    for (d, key) in dt_keys.iter().enumerate() {
+        groups.entry(*key).or_default().1.push(d);
+    }
+    let images: BTreeSet<i64> = groups.keys().map(|k| k.0).collect();
+    let classes: BTreeSet<i64> = groups.keys().map(|k| k.1).collect();
+
+    // np.linspace(0.0, 1.0, 101)
+    let rec_thresholds: Vec<f64> = (0..101)
+        .map(|i| if i == 100 { 1.0 } else { i as f64 * 0.01 })
+        .collect();
+    let max_det = max_dets.iter().max().copied().unwrap_or(0);
+    let (n_t, n_r, n_k) = (iou_thresholds.len(), rec_thresholds.len(), classes.len());
+    let (n_a, n_m) = (area_ranges.len(), max_dets.len());
+    let mut precision = ArrayD::<f64>::from_elem(IxDyn(&[n_t, n_r, n_k, n_a, n_m]), -1.0);
+    let mut recall = ArrayD::<f64>::from_elem(IxDyn(&[n_t, n_k, n_a, n_m]), -1.0);
+    let empty = (Vec::new(), Vec::new());
+
+    for (k, class) in classes.iter().enumerate() {
+        for (a, area_range) in area_ranges.iter().enumerate() {
+            let evals: Vec<ImageEval> = images
+                .iter()
+                .filter_map(|image| {
+                    let (gts, dts) = groups.get(&(*image, *class)).unwrap_or(&empty);
+                    evaluate_image(gts, dts, ground, dets, iou_thresholds, *area_range, max_det)
+                })
+                .collect();
+            let n_gt: usize = evals.iter().map(|e| e.n_gt).sum();
+            if n_gt == 0 {
+                continue;
+            }
+
+            for (m, max_det) in max_dets.iter().enumerate() {
+                // the top detections of every image, stable sorted by score
+                let mut order: Vec<(usize, usize)> = evals
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(e, ev)| (0..ev.scores.len().min(*max_det)).map(move |d| (e, d)))
+                    .collect();
+                order.sort_by(|x, y| evals[y.0].scores[y.1].total_cmp(&evals[x.0].scores[x.1]));
+
+                for t in 0..n_t {
+                    let (mut tp, mut fp) = (0.0, 0.0);
+                    let mut rc = Vec::with_capacity(order.len());
+                    let mut pr = Vec::with_capacity(order.len());
+                    for (e, d) in order.iter() {
+                        if !evals[*e].ignored[t][*d] {
+                            if evals[*e].matched[t][*d] {
+                                tp += 1.0;
+                            } else {
+                                fp += 1.0;
+                            }
+                        }
+                        rc.push(tp / n_gt as f64);
+                        pr.push(tp / (tp + fp + f64::EPSILON));
+                    }
+                    recall[[t, k, a, m]] = rc.last().copied().unwrap_or(0.0);
+
+                    // interpolated precision is non-increasing in recall
+                    for i in (1..pr.len()).rev() {
+                        if pr[i] > pr[i - 1] {
+                            pr[i - 1] = pr[i];
+                        }
+                    }
+                    for (r, threshold) in rec_thresholds.iter().enumerate() {
+                        let i = rc.partition_point(|x| x < threshold);
+                        precision[[t, r, k, a, m]] = if i < pr.len() { pr[i] } else { 0.0 };
+                    }
+                }
+            }
+        }
+    }
+    (precision, recall)
+}
@@ -212,6 +212,42 @@ macro_rules! numpy_dispatch_bool {
         }($arr)
     };
 
+    // single arg function with extra (non-dispatched) args
+    ($py:ident, $f:ident, $ret_type:ty, $arr:ident; $($extra:expr),+) => {
+        |x: &'a PyAny| -> $ret_type {
+            if let Ok(i) = x.extract::<numpy::PyReadonlyArrayDyn<bool>>() {
+                return $f::<bool>($py, i, $($extra),+);
+            }
+            if let Ok(i) = x.extract::<numpy::PyReadonlyArrayDyn<i8>>() {
+                return $f::<i8>($py, i, $($extra),+);
+            }
+            if let Ok(i) = x.extract::<numpy::PyReadonlyArrayDyn<i16>>() {
+                return $f::<i16>($py, i, $($extra),+);
+            }
+            if let Ok(i) = x.extract::<numpy::PyReadonlyArrayDyn<i32>>() {
+                return $f::<i32>($py, i, $($extra),+);
+            }
+            if let Ok(i) = x.extract::<numpy::PyReadonlyArrayDyn<i64>>() {
+                return $f::<i64>($py, i, $($extra),+);
+            }
+            if let Ok(i) = x.extract::<numpy::PyReadonlyArrayDyn<u8>>() {
+                return $f::<u8>($py, i, $($extra),+);
+            }
+            if let Ok(i) = x.extract::<numpy::PyReadonlyArrayDyn<u16>>() {
+                return $f::<u16>($py, i, $($extra),+);
+            }
+            if let Ok(i) = x.extract::<numpy::PyReadonlyArrayDyn<u32>>() {
+                return $f::<u32>($py, i, $($extra),+);
+            }
+            if let Ok(i) = x.extract::<numpy::PyReadonlyArrayDyn<u64>>() {
+                return $f::<u64>($py, i, $($extra),+);
+            }
+            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "Unsupported numpy dtype",
+            ))
+        }($arr)
+    };
+
     // two arg function
     ($py:ident, $f:ident, $ret_type:ty, $arr1:ident, $arr2:ident) => {
         |x: &'a PyAny, y: &'a PyAny| -> $ret_type {
@@ -275,6 +311,69 @@ macro_rules! numpy_dispatch_bool {
         }($arr1, $arr2)
     };
 
+    // two arg function with extra (non-dispatched) args
+    ($py:ident, $f:ident, $ret_type:ty, $arr1:ident, $arr2:ident; $($extra:expr),+) => {
+        |x: &'a PyAny, y: &'a PyAny| -> $ret_type {
+            if let (Ok(i), Ok(j)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<bool>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<bool>>(),
+            ) {
+                return $f::<bool>($py, i, j, $($extra),+);
+            }
+            if let (Ok(i), Ok(j)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<i8>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<i8>>(),
+            ) {
+                return $f::<i8>($py, i, j, $($extra),+);
+            }
+            if let (Ok(i), Ok(j)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<i16>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<i16>>(),
+            ) {
+                return $f::<i16>($py, i, j, $($extra),+);
+            }
+            if let (Ok(i), Ok(j)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<i32>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<i32>>(),
+            ) {
+                return $f::<i32>($py, i, j, $($extra),+);
+            }
+            if let (Ok(i), Ok(j)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<i64>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<i64>>(),
+            ) {
+                return $f::<i64>($py, i, j, $($extra),+);
+            }
+            if let (Ok(i), Ok(j)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<u8>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<u8>>(),
+            ) {
+                return $f::<u8>($py, i, j, $($extra),+);
+            }
+            if let (Ok(i), Ok(j)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<u16>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<u16>>(),
+            ) {
+                return $f::<u16>($py, i, j, $($extra),+);
+            }
+            if let (Ok(i), Ok(j)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<u32>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<u32>>(),
+            ) {
+                return $f::<u32>($py, i, j, $($extra),+);
+            }
+            if let (Ok(i), Ok(j)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<u64>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<u64>>(),
+            ) {
+                return $f::<u64>($py, i, j, $($extra),+);
+            }
+            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "Unsupported numpy dtype",
+            ))
+        }($arr1, $arr2)
+    };
+
     // three arg function
     ($py:ident, $f:ident, $ret_type:ty, $arr1:ident, $arr2:ident, $arr3:ident) => {
         |x: &'a PyAny, y: &'a PyAny, z: &'a PyAny| -> $ret_type {
@@ -346,4 +445,157 @@ macro_rules! numpy_dispatch_bool {
             ))
         }($arr1, $arr2, $arr3)
     };
+
+    // three arg function with extra (non-dispatched) args
+    ($py:ident, $f:ident, $ret_type:ty, $arr1:ident, $arr2:ident, $arr3:ident; $($extra:expr),+) => {
+        |x: &'a PyAny, y: &'a PyAny, z: &'a PyAny| -> $ret_type {
+            if let (Ok(i), Ok(j), Ok(k)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<bool>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<bool>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<bool>>(),
+            ) {
+                return $f::<bool>($py, i, j, k, $($extra),+);
+            }
+            if let (Ok(i), Ok(j), Ok(k)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<i8>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<i8>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<i8>>(),
+            ) {
+                return $f::<i8>($py, i, j, k, $($extra),+);
+            }
+            if let (Ok(i), Ok(j), Ok(k)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<i16>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<i16>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<i16>>(),
+            ) {
+                return $f::<i16>($py, i, j, k, $($extra),+);
+            }
+            if let (Ok(i), Ok(j), Ok(k)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<i32>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<i32>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<i32>>(),
+            ) {
+                return $f::<i32>($py, i, j, k, $($extra),+);
+            }
+            if let (Ok(i), Ok(j), Ok(k)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<i64>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<i64>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<i64>>(),
+            ) {
+                return $f::<i64>($py, i, j, k, $($extra),+);
+            }
+            if let (Ok(i), Ok(j), Ok(k)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<u8>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<u8>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<u8>>(),
+            ) {
+                return $f::<u8>($py, i, j, k, $($extra),+);
+            }
+            if let (Ok(i), Ok(j), Ok(k)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<u16>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<u16>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<u16>>(),
+            ) {
+                return $f::<u16>($py, i, j, k, $($extra),+);
+            }
+            if let (Ok(i), Ok(j), Ok(k)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<u32>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<u32>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<u32>>(),
+            ) {
+                return $f::<u32>($py, i, j, k, $($extra),+);
+            }
+            if let (Ok(i), Ok(j), Ok(k)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<u64>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<u64>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<u64>>(),
+            ) {
+                return $f::<u64>($py, i, j, k, $($extra),+);
+            }
+            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "Unsupported numpy dtype",
+            ))
+        }($arr1, $arr2, $arr3)
+    };
+
+    // four arg function
+    ($py:ident, $f:ident, $ret_type:ty, $arr1:ident, $arr2:ident, $arr3:ident, $arr4:ident) => {
+        |x: &'a PyAny, y: &'a PyAny, z: &'a PyAny, w: &'a PyAny| -> $ret_type {
+            if let (Ok(i), Ok(j), Ok(k), Ok(l)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<bool>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<bool>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<bool>>(),
+                w.extract::<numpy::PyReadonlyArrayDyn<bool>>(),
+            ) {
+                return $f::<bool>($py, i, j, k, l);
+            }
+            if let (Ok(i), Ok(j), Ok(k), Ok(l)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<i8>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<i8>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<i8>>(),
+                w.extract::<numpy::PyReadonlyArrayDyn<i8>>(),
+            ) {
+                return $f::<i8>($py, i, j, k, l);
+            }
+            if let (Ok(i), Ok(j), Ok(k), Ok(l)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<i16>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<i16>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<i16>>(),
+                w.extract::<numpy::PyReadonlyArrayDyn<i16>>(),
+            ) {
+                return $f::<i16>($py, i, j, k, l);
+            }
+            if let (Ok(i), Ok(j), Ok(k), Ok(l)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<i32>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<i32>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<i32>>(),
+                w.extract::<numpy::PyReadonlyArrayDyn<i32>>(),
+            ) {
+                return $f::<i32>($py, i, j, k, l);
+            }
+            if let (Ok(i), Ok(j), Ok(k), Ok(l)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<i64>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<i64>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<i64>>(),
+                w.extract::<numpy::PyReadonlyArrayDyn<i64>>(),
+            ) {
+                return $f::<i64>($py, i, j, k, l);
+            }
+            if let (Ok(i), Ok(j), Ok(k), Ok(l)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<u8>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<u8>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<u8>>(),
+                w.extract::<numpy::PyReadonlyArrayDyn<u8>>(),
+            ) {
+                return $f::<u8>($py, i, j, k, l);
+            }
+            if let (Ok(i), Ok(j), Ok(k), Ok(l)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<u16>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<u16>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<u16>>(),
+                w.extract::<numpy::PyReadonlyArrayDyn<u16>>(),
+            ) {
+                return $f::<u16>($py, i, j, k, l);
+            }
+            if let (Ok(i), Ok(j), Ok(k), Ok(l)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<u32>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<u32>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<u32>>(),
+                w.extract::<numpy::PyReadonlyArrayDyn<u32>>(),
+            ) {
+                return $f::<u32>($py, i, j, k, l);
+            }
+            if let (Ok(i), Ok(j), Ok(k), Ok(l)) = (
+                x.extract::<numpy::PyReadonlyArrayDyn<u64>>(),
+                y.extract::<numpy::PyReadonlyArrayDyn<u64>>(),
+                z.extract::<numpy::PyReadonlyArrayDyn<u64>>(),
+                w.extract::<numpy::PyReadonlyArrayDyn<u64>>(),
+            ) {
+                return $f::<u64>($py, i, j, k, l);
+            }
+            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "Unsupported numpy dtype",
+            ))
+        }($arr1, $arr2, $arr3, $arr4)
+    };
 }
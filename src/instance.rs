@@ -0,0 +1,203 @@
+use ndarray::{ArrayD, IxDyn};
+use numpy::*;
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::numpy_dispatch_bool;
+
+/// Connected-component labelling of a binary mask
+#[pyfunction]
+#[pyo3(name = "_label_components")]
+#[pyo3(text_signature = "(mask: np.ndarray, full: bool, /)")]
+pub fn py_label_components<'a>(
+    py: Python<'a>,
+    mask: &'a PyAny,
+    full: bool,
+) -> PyResult<(&'a PyArrayDyn<i64>, i64)> {
+    numpy_dispatch_bool!(
+        py,
+        label_components,
+        PyResult<(&'a PyArrayDyn<i64>, i64)>,
+        mask;
+        full
+    )
+}
+
+/// Object-level computational requirements
+#[pyfunction]
+#[pyo3(name = "_instance_reqs")]
+#[pyo3(
+    text_signature = "(actual: np.ndarray, pred: np.ndarray, iou_threshold: float, full: bool, /)"
+)]
+pub fn py_instance_reqs<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    pred: &'a PyAny,
+    iou_threshold: f64,
+    full: bool,
+) -> PyResult<(i64, i64, i64)> {
+    numpy_dispatch_bool!(
+        py,
+        instance_reqs,
+        PyResult<(i64, i64, i64)>,
+        actual,
+        pred;
+        iou_threshold,
+        full
+    )
+}
+
+/// Offsets to the face neighbours (4/6-connectivity) or to all 3^ndim - 1
+/// neighbours (8/26-connectivity) of a grid element
+fn neighbour_offsets(ndim: usize, full: bool) -> Vec<Vec<isize>> {
+    let mut offsets = vec![vec![]];
+    for _ in 0..ndim {
+        offsets = offsets
+            .into_iter()
+            .flat_map(|o: Vec<isize>| {
+                (-1..=1).map(move |d| {
+                    let mut o = o.clone();
+                    o.push(d);
+                    o
+                })
+            })
+            .collect();
+    }
+    offsets
+        .into_iter()
+        .filter(|o| {
+            let nonzero = o.iter().filter(|d| **d != 0).count();
+            nonzero > 0 && (full || nonzero == 1)
+        })
+        .collect()
+}
+
+/// Labels the connected foreground components of `mask` with 1..=n (background
+/// is 0) in order of their first element, returns the labels and n
+pub fn _label_components(mask: &ArrayD<bool>, full: bool) -> (ArrayD<i64>, i64) {
+    let shape = mask.shape().to_vec();
+    let ndim = shape.len();
+    let flat: Vec<bool> = mask.iter().cloned().collect();
+    let mut strides = vec![1; ndim];
+    for k in (1..ndim).rev() {
+        strides[k - 1] = strides[k] * shape[k];
+    }
+    let offsets = neighbour_offsets(ndim, full);
+
+    let mut labels = vec![0; flat.len()];
+    let mut n = 0;
+    let (mut stack, mut coord) = (Vec::new(), vec![0; ndim]);
+    for start in 0..flat.len() {
+        if !flat[start] || labels[start] != 0 {
+            continue;
+        }
+        n += 1;
+        labels[start] = n;
+        stack.push(start);
+        while let Some(p) = stack.pop() {
+            let mut rem = p;
+            for k in 0..ndim {
+                coord[k] = rem / strides[k];
+                rem %= strides[k];
+            }
+            'offsets: for offset in offsets.iter() {
+                let mut q = p as isize;
+                for k in 0..ndim {
+                    let c = coord[k] as isize + offset[k];
+                    if c < 0 || c >= shape[k] as isize {
+                        continue 'offsets;
+                    }
+                    q += offset[k] * strides[k] as isize;
+                }
+                let q = q as usize;
+                if flat[q] && labels[q] == 0 {
+                    labels[q] = n;
+                    stack.push(q);
+                }
+            }
+        }
+    }
+    (ArrayD::from_shape_vec(IxDyn(&shape), labels).unwrap(), n)
+}
+
+/// Number of matched component pairs, where pairs are matched one-to-one in
+/// order of descending IoU and pairs with IoU below `iou_threshold` never match
+fn match_components(
+    actual: &ArrayD<i64>,
+    n_actual: i64,
+    pred: &ArrayD<i64>,
+    n_pred: i64,
+    iou_threshold: f64,
+) -> i64 {
+    let mut actual_area = vec![0; n_actual as usize + 1];
+    let mut pred_area = vec![0; n_pred as usize + 1];
+    let mut overlap: HashMap<(i64, i64), i64> = HashMap::new();
+    for (a, p) in actual.iter().zip(pred.iter()) {
+        actual_area[*a as usize] += 1;
+        pred_area[*p as usize] += 1;
+        if *a != 0 && *p != 0 {
+            *overlap.entry((*a, *p)).or_insert(0) += 1;
+        }
+    }
+
+    let mut candidates: Vec<(f64, i64, i64)> = overlap
+        .into_iter()
+        .map(|((a, p), inter)| {
+            let union = actual_area[a as usize] + pred_area[p as usize] - inter;
+            (inter as f64 / union as f64, a, p)
+        })
+        .filter(|(iou, _, _)| *iou >= iou_threshold)
+        .collect();
+    candidates.sort_by(|x, y| {
+        y.0.partial_cmp(&x.0)
+            .unwrap()
+            .then(x.1.cmp(&y.1))
+            .then(x.2.cmp(&y.2))
+    });
+
+    let mut actual_used = vec![false; n_actual as usize + 1];
+    let mut pred_used = vec![false; n_pred as usize + 1];
+    let mut matched = 0;
+    for (_, a, p) in candidates {
+        if !actual_used[a as usize] && !pred_used[p as usize] {
+            actual_used[a as usize] = true;
+            pred_used[p as usize] = true;
+            matched += 1;
+        }
+    }
+    matched
+}
+
+fn label_components<'a, T>(
+    py: Python<'a>,
+    mask: PyReadonlyArrayDyn<T>,
+    full: bool,
+) -> PyResult<(&'a PyArrayDyn<i64>, i64)>
+where
+    T: Copy + numpy::Element + Into<i128>,
+{
+    let mask = mask.as_array().mapv(|x| x.into() != 0);
+    let (labels, n) = _label_components(&mask, full);
+    Ok((PyArrayDyn::from_array(py, &labels), n))
+}
+
+fn instance_reqs<T>(
+    _py: Python<'_>,
+    actual: PyReadonlyArrayDyn<T>,
+    pred: PyReadonlyArrayDyn<T>,
+    iou_threshold: f64,
+    full: bool,
+) -> PyResult<(i64, i64, i64)>
+where
+    T: Copy + numpy::Element + Into<i128>,
+{
+    if actual.shape() != pred.shape() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "actual and pred must be same shape",
+        ));
+    }
+    let (actual, n_actual) = _label_components(&actual.as_array().mapv(|x| x.into() != 0), full);
+    let (pred, n_pred) = _label_components(&pred.as_array().mapv(|x| x.into() != 0), full);
+    let tp = match_components(&actual, n_actual, &pred, n_pred, iou_threshold);
+    Ok((tp, n_actual, n_pred))
+}
@@ -0,0 +1,114 @@
+use ndarray::{Array2, ArrayView1};
+use numpy::*;
+use pyo3::prelude::*;
+use std::iter::zip;
+
+use crate::special::{beta_ppf, norm_ppf};
+
+/// (N, 2) confidence intervals of the proportions successes / trials
+#[pyfunction]
+#[pyo3(name = "_proportion_interval")]
+#[pyo3(
+    text_signature = "(successes: np.ndarray, trials: np.ndarray, confidence: float, method: str, /)"
+)]
+pub fn py_proportion_interval<'a>(
+    py: Python<'a>,
+    successes: PyReadonlyArray1<f64>,
+    trials: PyReadonlyArray1<f64>,
+    confidence: f64,
+    method: &str,
+) -> PyResult<&'a PyArray2<f64>> {
+    let method = match method {
+        "wilson" => Interval::Wilson,
+        "clopper_pearson" => Interval::ClopperPearson,
+        "agresti_coull" => Interval::AgrestiCoull,
+        _ => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "method must be one of wilson, clopper_pearson or agresti_coull",
+            ))
+        }
+    };
+    if !(confidence > 0.0 && confidence < 1.0) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "confidence must be in (0, 1)",
+        ));
+    }
+    let x = _proportion_interval(successes.as_array(), trials.as_array(), confidence, method)?;
+    Ok(PyArray2::from_array(py, &x))
+}
+
+#[derive(Clone, Copy)]
+pub enum Interval {
+    Wilson,
+    ClopperPearson,
+    AgrestiCoull,
+}
+
+/// Interval of one proportion, NaN without trials
+fn interval(x: f64, n: f64, alpha: f64, method: Interval) -> (f64, f64) {
+    if n == 0.0 {
+        return (f64::NAN, f64::NAN);
+    }
+    let z = norm_ppf(1.0 - alpha / 2.0);
+    let z2 = z * z;
+    match method {
+        Interval::Wilson => {
+            let p = x / n;
+            let denom = 1.0 + z2 / n;
+            let centre = (p + z2 / (2.0 * n)) / denom;
+            let half = z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / denom;
+            // the bounds at 0 or n are exactly the ends, rounding can miss them
+            let low = if x == 0.0 { 0.0 } else { centre - half };
+            let high = if x == n { 1.0 } else { centre + half };
+            (low.clamp(0.0, 1.0), high.clamp(0.0, 1.0))
+        }
+        // exact bounds from the beta quantiles, the bound at 0 or n is the end
+        Interval::ClopperPearson => {
+            let low = if x == 0.0 {
+                0.0
+            } else {
+                beta_ppf(alpha / 2.0, x, n - x + 1.0)
+            };
+            let high = if x == n {
+                1.0
+            } else {
+                beta_ppf(1.0 - alpha / 2.0, x + 1.0, n - x)
+            };
+            (low, high)
+        }
+        // Wald interval after adding z^2 / 2 successes and failures
+        Interval::AgrestiCoull => {
+            let n = n + z2;
+            let p = (x + z2 / 2.0) / n;
+            let half = z * (p * (1.0 - p) / n).sqrt();
+            ((p - half).max(0.0), (p + half).min(1.0))
+        }
+    }
+}
+
+/// Low and high bounds of the interval of every proportion
+pub fn _proportion_interval(
+    successes: ArrayView1<f64>,
+    trials: ArrayView1<f64>,
+    confidence: f64,
+    method: Interval,
+) -> PyResult<Array2<f64>> {
+    if successes.len() != trials.len() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "successes and trials must be same shape",
+        ));
+    }
+    if zip(successes.iter(), trials.iter()).any(|(x, n)| !(*x >= 0.0 && x <= n)) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "successes must be between 0 and trials",
+        ));
+    }
+    let alpha = 1.0 - confidence;
+    let mut x = Array2::<f64>::zeros((successes.len(), 2));
+    for (mut row, (s, n)) in zip(x.rows_mut(), zip(successes.iter(), trials.iter())) {
+        let (low, high) = interval(*s, *n, alpha, method);
+        row[0] = low;
+        row[1] = high;
+    }
+    Ok(x)
+}
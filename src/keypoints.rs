@@ -0,0 +1,102 @@
+use ndarray::{Array2, ArrayView2, Axis};
+use numpy::*;
+use pyo3::prelude::*;
+use std::iter::zip;
+
+/// Object keypoint similarity computational requirements
+#[pyfunction]
+#[pyo3(name = "_oks_reqs")]
+#[pyo3(
+    text_signature = "(actual: np.ndarray, pred: np.ndarray, areas: np.ndarray, sigmas: np.ndarray, /)"
+)]
+pub fn py_oks_reqs<'a>(
+    py: Python<'a>,
+    actual: PyReadonlyArray3<f64>,
+    pred: PyReadonlyArray3<f64>,
+    areas: PyReadonlyArray1<f64>,
+    sigmas: PyReadonlyArray1<f64>,
+) -> PyResult<&'a PyArray2<f64>> {
+    let (actual, pred) = (actual.as_array(), pred.as_array());
+    keypoints_check(actual.shape(), pred.shape(), areas.len())?;
+    if sigmas.len() != actual.shape()[1] {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "sigmas must have one entry per keypoint",
+        ));
+    }
+    // COCO uses the variance (2 * sigma)^2 of each keypoint
+    let vars: Vec<f64> = sigmas.as_array().iter().map(|s| 4.0 * s * s).collect();
+    let mut ret = Array2::<f64>::zeros((actual.shape()[0], 2));
+    for (((a, p), area), mut row) in zip(
+        zip(
+            zip(actual.outer_iter(), pred.outer_iter()),
+            areas.as_array().iter(),
+        ),
+        ret.outer_iter_mut(),
+    ) {
+        for (k, (d2, visible)) in squared_distances(a, p).into_iter().enumerate() {
+            if visible {
+                row[0] += (-d2 / vars[k] / (area + f64::EPSILON) / 2.0).exp();
+                row[1] += 1.0;
+            }
+        }
+    }
+    Ok(PyArray2::from_array(py, &ret))
+}
+
+/// Percentage of correct keypoints computational requirements
+#[pyfunction]
+#[pyo3(name = "_pck_reqs")]
+#[pyo3(text_signature = "(actual: np.ndarray, pred: np.ndarray, thresholds: np.ndarray, /)")]
+pub fn py_pck_reqs<'a>(
+    py: Python<'a>,
+    actual: PyReadonlyArray3<f64>,
+    pred: PyReadonlyArray3<f64>,
+    thresholds: PyReadonlyArray1<f64>,
+) -> PyResult<&'a PyArray2<i64>> {
+    let (actual, pred) = (actual.as_array(), pred.as_array());
+    keypoints_check(actual.shape(), pred.shape(), thresholds.len())?;
+    let mut ret = Array2::<i64>::zeros((actual.shape()[0], 2));
+    for (((a, p), threshold), mut row) in zip(
+        zip(
+            zip(actual.outer_iter(), pred.outer_iter()),
+            thresholds.as_array().iter(),
+        ),
+        ret.outer_iter_mut(),
+    ) {
+        let t2 = threshold * threshold;
+        for (d2, visible) in squared_distances(a, p) {
+            if visible {
+                row[0] += (d2 <= t2) as i64;
+                row[1] += 1;
+            }
+        }
+    }
+    Ok(PyArray2::from_array(py, &ret))
+}
+
+fn keypoints_check(actual: &[usize], pred: &[usize], n_samples: usize) -> PyResult<()> {
+    if actual[0] != pred[0] || actual[1] != pred[1] || pred[2] < 2 || !(2..=3).contains(&actual[2])
+    {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "keypoints must have shape (N, K, 2) or (N, K, 3)",
+        ));
+    }
+    if n_samples != actual[0] {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "expected one value per sample",
+        ));
+    }
+    Ok(())
+}
+
+/// Squared distance of every keypoint of a sample and whether it is visible in
+/// `actual` (no visibility column means every keypoint is visible)
+fn squared_distances(actual: ArrayView2<f64>, pred: ArrayView2<f64>) -> Vec<(f64, bool)> {
+    zip(actual.axis_iter(Axis(0)), pred.axis_iter(Axis(0)))
+        .map(|(a, p)| {
+            let (dx, dy) = (a[0] - p[0], a[1] - p[1]);
+            let visible = a.len() < 3 || a[2] > 0.0;
+            (dx * dx + dy * dy, visible)
+        })
+        .collect()
+}
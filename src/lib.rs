@@ -1,9 +1,29 @@
 use pyo3::prelude::*;
 
 mod binary;
+mod bootstrap;
+mod boundary;
+mod boxes;
 mod cm;
+mod compare;
+mod correlation;
+mod descriptive;
+mod detection;
 mod dispatch;
+mod instance;
+mod intervals;
+mod keypoints;
 mod multiclass;
+mod multilabel;
+mod nms;
+mod panoptic;
+mod probabilistic;
+mod quantiles;
+mod ranking;
+mod regression;
+mod special;
+mod surface;
+mod topk;
 mod utils;
 
 /// A Python module implemented in Rust.
@@ -11,19 +31,100 @@ mod utils;
 fn _fast_stats_ext(_py: Python, m: &PyModule) -> PyResult<()> {
     // cm
     m.add_function(wrap_pyfunction!(cm::py_confusion_matrix, m)?)?;
+    m.add_function(wrap_pyfunction!(cm::py_confusion_matrix_scores, m)?)?;
+    m.add_function(wrap_pyfunction!(cm::py_confusion_matrix_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(cm::py_confusion_matrix_grouped, m)?)?;
 
     // utils
     m.add_function(wrap_pyfunction!(utils::py_unique, m)?)?;
+    m.add_function(wrap_pyfunction!(utils::py_label_indices, m)?)?;
 
     // binary calcs
     m.add_function(wrap_pyfunction!(binary::py_binary_precision_reqs, m)?)?;
     m.add_function(wrap_pyfunction!(binary::py_binary_recall_reqs, m)?)?;
     m.add_function(wrap_pyfunction!(binary::py_binary_f1_score_reqs, m)?)?;
+    m.add_function(wrap_pyfunction!(binary::py_binary_f1_score_reqs_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(
+        binary::py_binary_f1_score_reqs_grouped,
+        m
+    )?)?;
 
     // multiclass calcs
     m.add_function(wrap_pyfunction!(multiclass::py_precision, m)?)?;
     m.add_function(wrap_pyfunction!(multiclass::py_recall, m)?)?;
     m.add_function(wrap_pyfunction!(multiclass::py_f1_score, m)?)?;
 
+    // multilabel calcs
+    m.add_function(wrap_pyfunction!(multilabel::py_multilabel_reqs, m)?)?;
+    m.add_function(wrap_pyfunction!(multilabel::py_multilabel_sample_reqs, m)?)?;
+
+    // top-k calcs
+    m.add_function(wrap_pyfunction!(topk::py_top_k_reqs, m)?)?;
+
+    // boundary calcs
+    m.add_function(wrap_pyfunction!(boundary::py_boundary, m)?)?;
+    m.add_function(wrap_pyfunction!(boundary::py_boundary_f1_reqs, m)?)?;
+    m.add_function(wrap_pyfunction!(boundary::py_boundary_iou_reqs, m)?)?;
+
+    // surface distance calcs
+    m.add_function(wrap_pyfunction!(surface::py_surface_distances, m)?)?;
+
+    // instance calcs
+    m.add_function(wrap_pyfunction!(instance::py_label_components, m)?)?;
+    m.add_function(wrap_pyfunction!(instance::py_instance_reqs, m)?)?;
+
+    // panoptic calcs
+    m.add_function(wrap_pyfunction!(panoptic::py_panoptic_reqs, m)?)?;
+
+    // box calcs
+    m.add_function(wrap_pyfunction!(boxes::py_box_iou, m)?)?;
+    m.add_function(wrap_pyfunction!(nms::py_nms, m)?)?;
+    m.add_function(wrap_pyfunction!(nms::py_soft_nms, m)?)?;
+
+    // detection calcs
+    m.add_function(wrap_pyfunction!(detection::py_detection_eval, m)?)?;
+
+    // keypoint calcs
+    m.add_function(wrap_pyfunction!(keypoints::py_oks_reqs, m)?)?;
+    m.add_function(wrap_pyfunction!(keypoints::py_pck_reqs, m)?)?;
+
+    // regression calcs
+    m.add_function(wrap_pyfunction!(regression::py_regression_reqs, m)?)?;
+
+    // descriptive calcs
+    m.add_function(wrap_pyfunction!(descriptive::py_moments, m)?)?;
+    m.add_function(wrap_pyfunction!(descriptive::py_merge_moments, m)?)?;
+
+    // quantile calcs
+    m.add_function(wrap_pyfunction!(quantiles::py_quantile, m)?)?;
+    m.add_function(wrap_pyfunction!(quantiles::py_tdigest_update, m)?)?;
+    m.add_function(wrap_pyfunction!(quantiles::py_tdigest_merge, m)?)?;
+    m.add_function(wrap_pyfunction!(quantiles::py_tdigest_quantile, m)?)?;
+    m.add_function(wrap_pyfunction!(quantiles::py_histogram, m)?)?;
+
+    // correlation calcs
+    m.add_function(wrap_pyfunction!(correlation::py_correlation, m)?)?;
+
+    // ranking calcs
+    m.add_function(wrap_pyfunction!(ranking::py_ranking_reqs, m)?)?;
+    m.add_function(wrap_pyfunction!(ranking::py_ranking_reqs_grouped, m)?)?;
+
+    // probabilistic calcs
+    m.add_function(wrap_pyfunction!(probabilistic::py_log_loss_sum, m)?)?;
+    m.add_function(wrap_pyfunction!(probabilistic::py_brier_sum, m)?)?;
+    m.add_function(wrap_pyfunction!(probabilistic::py_calibration_reqs, m)?)?;
+
+    // bootstrap calcs
+    m.add_function(wrap_pyfunction!(bootstrap::py_bootstrap_totals, m)?)?;
+    m.add_function(wrap_pyfunction!(bootstrap::py_bootstrap_interval, m)?)?;
+
+    // interval calcs
+    m.add_function(wrap_pyfunction!(intervals::py_proportion_interval, m)?)?;
+
+    // compare calcs
+    m.add_function(wrap_pyfunction!(compare::py_paired_counts, m)?)?;
+    m.add_function(wrap_pyfunction!(compare::py_paired_permutation_reqs, m)?)?;
+    m.add_function(wrap_pyfunction!(compare::py_mcnemar, m)?)?;
+
     Ok(())
 }
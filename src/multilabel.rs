@@ -0,0 +1,100 @@
+use numpy::*;
+use pyo3::prelude::*;
+use std::iter::zip;
+
+use crate::numpy_dispatch_bool;
+
+/// Multilabel per-label computational requirements
+#[pyfunction]
+#[pyo3(name = "_multilabel_reqs")]
+#[pyo3(text_signature = "(actual: np.ndarray, pred: np.ndarray, /)")]
+pub fn py_multilabel_reqs<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    pred: &'a PyAny,
+) -> PyResult<&'a PyArray2<i64>> {
+    numpy_dispatch_bool!(
+        py,
+        multilabel_reqs,
+        PyResult<&'a PyArray2<i64>>,
+        actual,
+        pred
+    )
+}
+
+/// Multilabel per-sample computational requirements
+#[pyfunction]
+#[pyo3(name = "_multilabel_sample_reqs")]
+#[pyo3(text_signature = "(actual: np.ndarray, pred: np.ndarray, /)")]
+pub fn py_multilabel_sample_reqs<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    pred: &'a PyAny,
+) -> PyResult<&'a PyArray2<i64>> {
+    numpy_dispatch_bool!(
+        py,
+        multilabel_sample_reqs,
+        PyResult<&'a PyArray2<i64>>,
+        actual,
+        pred
+    )
+}
+
+fn label_axis(ndim: usize) -> PyResult<ndarray::Axis> {
+    if ndim == 0 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "indicator arrays must have a label axis",
+        ));
+    }
+    Ok(ndarray::Axis(ndim - 1))
+}
+
+/// TP, FP, FN and TN for every label (last axis) of an indicator array
+fn multilabel_reqs<'a, T>(
+    py: Python<'a>,
+    actual: PyReadonlyArrayDyn<T>,
+    pred: PyReadonlyArrayDyn<T>,
+) -> PyResult<&'a PyArray2<i64>>
+where
+    T: Copy + numpy::Element + Into<i128>,
+{
+    let (actual, pred) = (actual.as_array(), pred.as_array());
+    let axis = label_axis(actual.ndim())?;
+    let mut ret = ndarray::Array2::<i64>::zeros((actual.len_of(axis), 4));
+    for (r1, r2) in zip(actual.lanes(axis), pred.lanes(axis)) {
+        for (idx, (a, p)) in zip(r1, r2).enumerate() {
+            let col = match ((*a).into() != 0, (*p).into() != 0) {
+                (true, true) => 0,
+                (false, true) => 1,
+                (true, false) => 2,
+                (false, false) => 3,
+            };
+            ret[(idx, col)] += 1;
+        }
+    }
+    Ok(PyArray2::from_array(py, &ret))
+}
+
+/// TP, TP + FP and TP + FN for every sample (lane along the last axis)
+fn multilabel_sample_reqs<'a, T>(
+    py: Python<'a>,
+    actual: PyReadonlyArrayDyn<T>,
+    pred: PyReadonlyArrayDyn<T>,
+) -> PyResult<&'a PyArray2<i64>>
+where
+    T: Copy + numpy::Element + Into<i128>,
+{
+    let (actual, pred) = (actual.as_array(), pred.as_array());
+    let axis = label_axis(actual.ndim())?;
+    let n_samples = actual.shape()[..axis.index()].iter().product::<usize>();
+    let mut ret = ndarray::Array2::<i64>::zeros((n_samples, 3));
+    for (mut row, (r1, r2)) in zip(ret.rows_mut(), zip(actual.lanes(axis), pred.lanes(axis))) {
+        for (a, p) in zip(r1, r2) {
+            let (a, p) = ((*a).into() != 0, (*p).into() != 0);
+            row[0] += (a && p) as i64;
+            row[1] += p as i64;
+            row[2] += a as i64;
+        }
+    }
+    Ok(PyArray2::from_array(py, &ret))
+}
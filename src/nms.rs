@@ -0,0 +1,144 @@
+use numpy::*;
+use pyo3::prelude::*;
+
+use crate::boxes::{pair_iou, BoxGeom, IouKind};
+
+/// Non-maximum suppression, optionally per label
+#[pyfunction]
+#[pyo3(name = "_nms")]
+#[pyo3(
+    text_signature = "(boxes: np.ndarray, scores: np.ndarray, labels: Optional[np.ndarray], iou_threshold: float, /)"
+)]
+pub fn py_nms<'a>(
+    py: Python<'a>,
+    boxes: PyReadonlyArray2<f64>,
+    scores: PyReadonlyArray1<f64>,
+    labels: Option<PyReadonlyArray1<i64>>,
+    iou_threshold: f64,
+) -> PyResult<&'a PyArray1<i64>> {
+    let (boxes, scores) = to_geoms(boxes, scores)?;
+    let labels = match labels {
+        Some(l) if l.len() != boxes.len() => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "labels must have one entry per box",
+            ))
+        }
+        Some(l) => l.to_vec()?,
+        None => vec![0; boxes.len()],
+    };
+    let keep = _nms(&boxes, &scores, &labels, iou_threshold);
+    Ok(PyArray1::from_vec(py, keep))
+}
+
+/// Soft non-maximum suppression
+#[pyfunction]
+#[pyo3(name = "_soft_nms")]
+#[pyo3(
+    text_signature = "(boxes: np.ndarray, scores: np.ndarray, iou_threshold: float, sigma: float, score_threshold: float, linear: bool, /)"
+)]
+pub fn py_soft_nms<'a>(
+    py: Python<'a>,
+    boxes: PyReadonlyArray2<f64>,
+    scores: PyReadonlyArray1<f64>,
+    iou_threshold: f64,
+    sigma: f64,
+    score_threshold: f64,
+    linear: bool,
+) -> PyResult<(&'a PyArray1<i64>, &'a PyArray1<f64>)> {
+    let (boxes, scores) = to_geoms(boxes, scores)?;
+    let (keep, kept_scores) = _soft_nms(
+        &boxes,
+        scores,
+        iou_threshold,
+        sigma,
+        score_threshold,
+        linear,
+    );
+    Ok((
+        PyArray1::from_vec(py, keep),
+        PyArray1::from_vec(py, kept_scores),
+    ))
+}
+
+fn to_geoms(
+    boxes: PyReadonlyArray2<f64>,
+    scores: PyReadonlyArray1<f64>,
+) -> PyResult<(Vec<BoxGeom>, Vec<f64>)> {
+    let boxes = boxes.as_array();
+    if boxes.shape()[1] != 4 || boxes.shape()[0] != scores.len() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "boxes must have shape (N, 4) with one score per box",
+        ));
+    }
+    let geoms = boxes.rows().into_iter().map(BoxGeom::new).collect();
+    Ok((geoms, scores.to_vec()?))
+}
+
+/// Indices of the kept boxes in order of descending score, a box is suppressed
+/// by a kept box of the same label with a higher score and IoU > `iou_threshold`
+pub fn _nms(boxes: &[BoxGeom], scores: &[f64], labels: &[i64], iou_threshold: f64) -> Vec<i64> {
+    let mut order: Vec<usize> = (0..boxes.len()).collect();
+    order.sort_by(|x, y| scores[*y].total_cmp(&scores[*x]));
+
+    let mut suppressed = vec![false; boxes.len()];
+    let mut keep = Vec::new();
+    for (n, i) in order.iter().enumerate() {
+        if suppressed[*i] {
+            continue;
+        }
+        keep.push(*i as i64);
+        for j in order[n + 1..].iter() {
+            if !suppressed[*j]
+                && labels[*i] == labels[*j]
+                && pair_iou(&boxes[*i], &boxes[*j], IouKind::Iou) > iou_threshold
+            {
+                suppressed[*j] = true;
+            }
+        }
+    }
+    keep
+}
+
+/// Soft-NMS (Bodla et al.): the highest scoring box is kept and the scores of the
+/// remaining boxes decay with their IoU to it, either linearly above
+/// `iou_threshold` or by the Gaussian exp(-IoU^2 / sigma), until every score is
+/// below `score_threshold`. Returns kept indices in order of selection with
+/// their decayed scores.
+pub fn _soft_nms(
+    boxes: &[BoxGeom],
+    mut scores: Vec<f64>,
+    iou_threshold: f64,
+    sigma: f64,
+    score_threshold: f64,
+    linear: bool,
+) -> (Vec<i64>, Vec<f64>) {
+    let mut remaining: Vec<usize> = (0..boxes.len())
+        .filter(|i| scores[*i] >= score_threshold)
+        .collect();
+    let (mut keep, mut kept_scores) = (Vec::new(), Vec::new());
+    while !remaining.is_empty() {
+        // first index of the highest score
+        let mut best = 0;
+        for (n, i) in remaining.iter().enumerate() {
+            if scores[*i] > scores[remaining[best]] {
+                best = n;
+            }
+        }
+        let m = remaining.remove(best);
+        keep.push(m as i64);
+        kept_scores.push(scores[m]);
+
+        for i in remaining.iter() {
+            let iou = pair_iou(&boxes[m], &boxes[*i], IouKind::Iou);
+            if linear {
+                if iou > iou_threshold {
+                    scores[*i] *= 1.0 - iou;
+                }
+            } else {
+                scores[*i] *= (-iou * iou / sigma).exp();
+            }
+        }
+        remaining.retain(|i| scores[*i] >= score_threshold);
+    }
+    (keep, kept_scores)
+}
@@ -0,0 +1,114 @@
+use ndarray::{Array2, Zip};
+use numpy::*;
+use pyo3::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// A segment is identified by its (class, instance id) pair
+type Segment = (i64, i64);
+
+/// Panoptic quality computational requirements
+#[pyfunction]
+#[pyo3(name = "_panoptic_reqs")]
+#[pyo3(
+    text_signature = "(true_instances: np.ndarray, true_classes: np.ndarray, pred_instances: np.ndarray, pred_classes: np.ndarray, labels: np.ndarray, void_label: Optional[int], /)"
+)]
+pub fn py_panoptic_reqs<'a>(
+    py: Python<'a>,
+    true_instances: PyReadonlyArrayDyn<i64>,
+    true_classes: PyReadonlyArrayDyn<i64>,
+    pred_instances: PyReadonlyArrayDyn<i64>,
+    pred_classes: PyReadonlyArrayDyn<i64>,
+    labels: PyReadonlyArray1<i64>,
+    void_label: Option<i64>,
+) -> PyResult<&'a PyArray2<f64>> {
+    let shape = true_instances.shape();
+    if [&true_classes, &pred_instances, &pred_classes]
+        .iter()
+        .any(|arr| arr.shape() != shape)
+    {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "instance and class maps must be same shape",
+        ));
+    }
+    let x = _panoptic_reqs(
+        true_instances,
+        true_classes,
+        pred_instances,
+        pred_classes,
+        labels,
+        void_label,
+    );
+    Ok(PyArray2::from_array(py, &x))
+}
+
+/// Per label (TP, FP, FN, sum of matched IoUs), where segments of the same class
+/// match at IoU > 0.5. Pixels of the void label in the truth are left out of the
+/// IoU and predicted segments that are mostly void are not counted as FP.
+pub fn _panoptic_reqs(
+    true_instances: PyReadonlyArrayDyn<i64>,
+    true_classes: PyReadonlyArrayDyn<i64>,
+    pred_instances: PyReadonlyArrayDyn<i64>,
+    pred_classes: PyReadonlyArrayDyn<i64>,
+    labels: PyReadonlyArray1<i64>,
+    void_label: Option<i64>,
+) -> Array2<f64> {
+    let mut true_area: HashMap<Segment, i64> = HashMap::new();
+    let mut pred_area: HashMap<Segment, i64> = HashMap::new();
+    let mut pred_void: HashMap<Segment, i64> = HashMap::new();
+    let mut overlap: HashMap<(Segment, Segment), i64> = HashMap::new();
+    Zip::from(&true_classes.as_array())
+        .and(&true_instances.as_array())
+        .and(&pred_classes.as_array())
+        .and(&pred_instances.as_array())
+        .for_each(|&tc, &ti, &pc, &pi| {
+            let (t_void, p_void) = (Some(tc) == void_label, Some(pc) == void_label);
+            if !t_void {
+                *true_area.entry((tc, ti)).or_insert(0) += 1;
+            }
+            if !p_void {
+                *pred_area.entry((pc, pi)).or_insert(0) += 1;
+                if t_void {
+                    *pred_void.entry((pc, pi)).or_insert(0) += 1;
+                }
+            }
+            if !t_void && !p_void && tc == pc {
+                *overlap.entry(((tc, ti), (pc, pi))).or_insert(0) += 1;
+            }
+        });
+
+    let labels = labels.as_array();
+    let idx_map: HashMap<i64, usize> =
+        HashMap::from_iter(labels.iter().enumerate().map(|(x, y)| (*y, x)));
+    let mut x = Array2::<f64>::zeros((labels.len(), 4));
+    let mut true_matched: HashSet<Segment> = HashSet::new();
+    let mut pred_matched: HashSet<Segment> = HashSet::new();
+
+    // IoU > 0.5 makes every match unique
+    for ((t, p), inter) in overlap {
+        let void = pred_void.get(&p).unwrap_or(&0);
+        let union = true_area[&t] + pred_area[&p] - inter - void;
+        let iou = inter as f64 / union as f64;
+        if iou > 0.5 {
+            true_matched.insert(t);
+            pred_matched.insert(p);
+            if let Some(k) = idx_map.get(&t.0) {
+                x[(*k, 0)] += 1.0;
+                x[(*k, 3)] += iou;
+            }
+        }
+    }
+    for (t, _) in true_area.iter().filter(|(t, _)| !true_matched.contains(t)) {
+        if let Some(k) = idx_map.get(&t.0) {
+            x[(*k, 2)] += 1.0;
+        }
+    }
+    for (p, area) in pred_area.iter().filter(|(p, _)| !pred_matched.contains(p)) {
+        if 2 * pred_void.get(p).unwrap_or(&0) > *area {
+            continue;
+        }
+        if let Some(k) = idx_map.get(&p.0) {
+            x[(*k, 1)] += 1.0;
+        }
+    }
+    x
+}
@@ -0,0 +1,161 @@
+use ndarray::{Array2, ArrayView1, ArrayView2};
+use numpy::*;
+use pyo3::prelude::*;
+use std::iter::zip;
+
+/// Sum of the log losses of every sample
+#[pyfunction]
+#[pyo3(name = "_log_loss_sum")]
+#[pyo3(text_signature = "(actual: np.ndarray, prob: np.ndarray, eps: float, /)")]
+pub fn py_log_loss_sum(actual: PyReadonlyArray1<i64>, prob: &PyAny, eps: f64) -> PyResult<f64> {
+    let actual = actual.as_array();
+    if let Ok(p) = prob.extract::<PyReadonlyArray2<f32>>() {
+        return _log_loss_sum(actual, p.as_array(), eps);
+    }
+    if let Ok(p) = prob.extract::<PyReadonlyArray2<f64>>() {
+        return _log_loss_sum(actual, p.as_array(), eps);
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        "Unsupported numpy dtype",
+    ))
+}
+
+/// Sum of the Brier scores of every sample
+#[pyfunction]
+#[pyo3(name = "_brier_sum")]
+#[pyo3(text_signature = "(actual: np.ndarray, prob: np.ndarray, /)")]
+pub fn py_brier_sum(actual: PyReadonlyArray1<i64>, prob: &PyAny) -> PyResult<f64> {
+    let actual = actual.as_array();
+    if let Ok(p) = prob.extract::<PyReadonlyArray2<f32>>() {
+        return _brier_sum(actual, p.as_array());
+    }
+    if let Ok(p) = prob.extract::<PyReadonlyArray2<f64>>() {
+        return _brier_sum(actual, p.as_array());
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        "Unsupported numpy dtype",
+    ))
+}
+
+/// Calibration computational requirements per confidence bin
+#[pyfunction]
+#[pyo3(name = "_calibration_reqs")]
+#[pyo3(text_signature = "(actual: np.ndarray, prob: np.ndarray, edges: List[float], /)")]
+pub fn py_calibration_reqs<'a>(
+    py: Python<'a>,
+    actual: PyReadonlyArray1<i64>,
+    prob: &'a PyAny,
+    edges: Vec<f64>,
+) -> PyResult<&'a PyArray2<f64>> {
+    if edges.len() < 2 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "edges must have at least 2 values",
+        ));
+    }
+    let actual = actual.as_array();
+    if let Ok(p) = prob.extract::<PyReadonlyArray2<f32>>() {
+        let x = _calibration_reqs(actual, p.as_array(), &edges)?;
+        return Ok(PyArray2::from_array(py, &x));
+    }
+    if let Ok(p) = prob.extract::<PyReadonlyArray2<f64>>() {
+        let x = _calibration_reqs(actual, p.as_array(), &edges)?;
+        return Ok(PyArray2::from_array(py, &x));
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        "Unsupported numpy dtype",
+    ))
+}
+
+fn probability_check<F>(actual: ArrayView1<i64>, prob: ArrayView2<F>) -> PyResult<()> {
+    if actual.len() != prob.nrows() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "expected one row of probabilities per sample",
+        ));
+    }
+    let n_classes = prob.ncols().max(2) as i64;
+    if actual.iter().any(|a| *a < 0 || *a >= n_classes) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "y_true values must be one of the labels",
+        ));
+    }
+    Ok(())
+}
+
+/// Probability of the true class, a single column is the probability of the
+/// positive class (index 1)
+fn true_class_probability<F: Copy + Into<f64>>(actual: i64, row: ArrayView1<F>) -> f64 {
+    if row.len() == 1 {
+        let p: f64 = row[0].into();
+        return if actual == 1 { p } else { 1.0 - p };
+    }
+    row[actual as usize].into()
+}
+
+pub fn _log_loss_sum<F>(actual: ArrayView1<i64>, prob: ArrayView2<F>, eps: f64) -> PyResult<f64>
+where
+    F: Copy + Into<f64>,
+{
+    probability_check(actual, prob)?;
+    Ok(zip(actual.iter(), prob.rows())
+        .map(|(a, row)| -true_class_probability(*a, row).clamp(eps, 1.0 - eps).ln())
+        .sum())
+}
+
+/// Squared error of the probabilities summed over the classes, or of the
+/// positive class probability for a single column
+pub fn _brier_sum<F>(actual: ArrayView1<i64>, prob: ArrayView2<F>) -> PyResult<f64>
+where
+    F: Copy + Into<f64>,
+{
+    probability_check(actual, prob)?;
+    Ok(zip(actual.iter(), prob.rows())
+        .map(|(a, row)| {
+            if row.len() == 1 {
+                let p: f64 = row[0].into();
+                return (p - (*a == 1) as i64 as f64).powi(2);
+            }
+            row.iter()
+                .enumerate()
+                .map(|(c, p)| ((*p).into() - (c as i64 == *a) as i64 as f64).powi(2))
+                .sum()
+        })
+        .sum())
+}
+
+/// (B, 3) array of the count, summed confidence and summed correctness of the
+/// samples in every bin. With a single column the confidence is the positive
+/// class probability and correct means positive, otherwise the confidence is the
+/// top probability and correct means its class is the true one. A confidence on
+/// an inner edge goes to the lower bin (like `np.searchsorted` in sklearn) and
+/// confidences outside the edges go to the first or last bin.
+pub fn _calibration_reqs<F>(
+    actual: ArrayView1<i64>,
+    prob: ArrayView2<F>,
+    edges: &[f64],
+) -> PyResult<Array2<f64>>
+where
+    F: Copy + Into<f64>,
+{
+    probability_check(actual, prob)?;
+    let n_bins = edges.len() - 1;
+    let inner = &edges[1..n_bins];
+    let mut reqs = Array2::<f64>::zeros((n_bins, 3));
+    for (a, row) in zip(actual.iter(), prob.rows()) {
+        let (confidence, correct) = if row.len() == 1 {
+            (row[0].into(), *a == 1)
+        } else {
+            let mut top = 0;
+            for (c, p) in row.iter().enumerate() {
+                if (*p).into() > row[top].into() {
+                    top = c;
+                }
+            }
+            (row[top].into(), top as i64 == *a)
+        };
+        let bin = inner.partition_point(|e| *e < confidence);
+        reqs[[bin, 0]] += 1.0;
+        reqs[[bin, 1]] += confidence;
+        reqs[[bin, 2]] += correct as i64 as f64;
+    }
+    Ok(reqs)
+}
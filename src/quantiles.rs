@@ -0,0 +1,306 @@
+use ndarray::{Array1, Array2, ArrayD, ArrayView2, ArrayViewD, Axis, IxDyn};
+use numpy::*;
+use pyo3::prelude::*;
+
+/// Exact quantiles by selection, optionally per lane along `axis`
+#[pyfunction]
+#[pyo3(name = "_quantile")]
+#[pyo3(text_signature = "(arr: np.ndarray, qs: List[float], axis: Optional[int], method: str, /)")]
+pub fn py_quantile<'a>(
+    py: Python<'a>,
+    arr: &'a PyAny,
+    qs: Vec<f64>,
+    axis: Option<usize>,
+    method: &str,
+) -> PyResult<&'a PyArrayDyn<f64>> {
+    let method = match method {
+        "linear" => Interpolation::Linear,
+        "lower" => Interpolation::Lower,
+        "higher" => Interpolation::Higher,
+        "midpoint" => Interpolation::Midpoint,
+        _ => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "method must be one of linear, lower, higher or midpoint",
+            ))
+        }
+    };
+    if let Ok(a) = arr.extract::<PyReadonlyArrayDyn<f32>>() {
+        let x = _quantile(a.as_array(), &qs, axis, method)?;
+        return Ok(PyArrayDyn::from_array(py, &x));
+    }
+    if let Ok(a) = arr.extract::<PyReadonlyArrayDyn<f64>>() {
+        let x = _quantile(a.as_array(), &qs, axis, method)?;
+        return Ok(PyArrayDyn::from_array(py, &x));
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        "Unsupported numpy dtype",
+    ))
+}
+
+/// Adds the values of an array to t-digest centroids
+#[pyfunction]
+#[pyo3(name = "_tdigest_update")]
+#[pyo3(text_signature = "(centroids: np.ndarray, arr: np.ndarray, compression: float, /)")]
+pub fn py_tdigest_update<'a>(
+    py: Python<'a>,
+    centroids: PyReadonlyArray2<f64>,
+    arr: &'a PyAny,
+    compression: f64,
+) -> PyResult<(&'a PyArray2<f64>, f64, f64)> {
+    let mut merged = to_centroids(centroids.as_array())?;
+    let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+    let mut add = |x: f64| {
+        if !x.is_nan() {
+            merged.push((x, 1.0));
+            min = min.min(x);
+            max = max.max(x);
+        }
+    };
+    if let Ok(a) = arr.extract::<PyReadonlyArrayDyn<f32>>() {
+        a.as_array().iter().for_each(|x| add(*x as f64));
+    } else if let Ok(a) = arr.extract::<PyReadonlyArrayDyn<f64>>() {
+        a.as_array().iter().for_each(|x| add(*x));
+    } else {
+        return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+            "Unsupported numpy dtype",
+        ));
+    }
+    let x = from_centroids(&_tdigest_compress(merged, compression));
+    Ok((PyArray2::from_array(py, &x), min, max))
+}
+
+/// Combination of the centroids of two t-digests
+#[pyfunction]
+#[pyo3(name = "_tdigest_merge")]
+#[pyo3(text_signature = "(a: np.ndarray, b: np.ndarray, compression: float, /)")]
+pub fn py_tdigest_merge<'a>(
+    py: Python<'a>,
+    a: PyReadonlyArray2<f64>,
+    b: PyReadonlyArray2<f64>,
+    compression: f64,
+) -> PyResult<&'a PyArray2<f64>> {
+    let mut merged = to_centroids(a.as_array())?;
+    merged.extend(to_centroids(b.as_array())?);
+    let x = from_centroids(&_tdigest_compress(merged, compression));
+    Ok(PyArray2::from_array(py, &x))
+}
+
+/// Quantiles estimated from t-digest centroids
+#[pyfunction]
+#[pyo3(name = "_tdigest_quantile")]
+#[pyo3(text_signature = "(centroids: np.ndarray, min: float, max: float, qs: List[float], /)")]
+pub fn py_tdigest_quantile<'a>(
+    py: Python<'a>,
+    centroids: PyReadonlyArray2<f64>,
+    min: f64,
+    max: f64,
+    qs: Vec<f64>,
+) -> PyResult<&'a PyArray1<f64>> {
+    let centroids = to_centroids(centroids.as_array())?;
+    let x = _tdigest_quantile(&centroids, min, max, &qs);
+    Ok(PyArray1::from_vec(py, x))
+}
+
+/// Counts of the values of an array in the bins between increasing edges
+#[pyfunction]
+#[pyo3(name = "_histogram")]
+#[pyo3(text_signature = "(arr: np.ndarray, edges: List[float], /)")]
+pub fn py_histogram<'a>(
+    py: Python<'a>,
+    arr: &'a PyAny,
+    edges: Vec<f64>,
+) -> PyResult<&'a PyArray1<i64>> {
+    if edges.len() < 2 || edges.windows(2).any(|e| e[0] >= e[1]) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "edges must be at least 2 strictly increasing values",
+        ));
+    }
+    if let Ok(a) = arr.extract::<PyReadonlyArrayDyn<f32>>() {
+        let x = _histogram(a.as_array(), &edges);
+        return Ok(PyArray1::from_array(py, &x));
+    }
+    if let Ok(a) = arr.extract::<PyReadonlyArrayDyn<f64>>() {
+        let x = _histogram(a.as_array(), &edges);
+        return Ok(PyArray1::from_array(py, &x));
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        "Unsupported numpy dtype",
+    ))
+}
+
+#[derive(Clone, Copy)]
+pub enum Interpolation {
+    Linear,
+    Lower,
+    Higher,
+    Midpoint,
+}
+
+/// Quantiles of `values` like `np.quantile`, each found by partial selection so
+/// `values` ends up reordered. Lanes with NaNs or no values give NaN.
+fn lane_quantiles(values: &mut [f64], qs: &[f64], method: Interpolation) -> Vec<f64> {
+    let n = values.len();
+    if n == 0 || values.iter().any(|x| x.is_nan()) {
+        return vec![f64::NAN; qs.len()];
+    }
+    qs.iter()
+        .map(|q| {
+            let position = q * (n - 1) as f64;
+            let k = (position.floor() as usize).min(n - 1);
+            let (_, lower, right) = values.select_nth_unstable_by(k, f64::total_cmp);
+            let lower = *lower;
+            let fraction = position - k as f64;
+            if fraction == 0.0 {
+                return lower;
+            }
+            let higher = right.iter().copied().fold(f64::INFINITY, f64::min);
+            match method {
+                Interpolation::Linear => lower + (higher - lower) * fraction,
+                Interpolation::Lower => lower,
+                Interpolation::Higher => higher,
+                Interpolation::Midpoint => (lower + higher) / 2.0,
+            }
+        })
+        .collect()
+}
+
+/// Quantiles (Q,) of the whole array, or (..., Q) with `axis` reduced
+pub fn _quantile<F>(
+    arr: ArrayViewD<F>,
+    qs: &[f64],
+    axis: Option<usize>,
+    method: Interpolation,
+) -> PyResult<ArrayD<f64>>
+where
+    F: Copy + Into<f64>,
+{
+    let axis = match axis {
+        None => {
+            let mut values: Vec<f64> = arr.iter().map(|x| (*x).into()).collect();
+            return Ok(Array1::from_vec(lane_quantiles(&mut values, qs, method)).into_dyn());
+        }
+        Some(a) if a >= arr.ndim() => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "axis is out of bounds for the array",
+            ))
+        }
+        Some(a) => a,
+    };
+
+    let mut shape = arr.shape().to_vec();
+    shape.remove(axis);
+    shape.push(qs.len());
+    let mut ret = Vec::with_capacity(shape.iter().product());
+    let mut values = Vec::with_capacity(arr.shape()[axis]);
+    for lane in arr.lanes(Axis(axis)) {
+        values.clear();
+        values.extend(lane.iter().map(|x| (*x).into()));
+        ret.extend(lane_quantiles(&mut values, qs, method));
+    }
+    Ok(ArrayD::from_shape_vec(IxDyn(&shape), ret).unwrap())
+}
+
+fn to_centroids(arr: ArrayView2<f64>) -> PyResult<Vec<(f64, f64)>> {
+    if arr.shape()[1] != 2 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "centroids must have shape (K, 2)",
+        ));
+    }
+    Ok(arr.rows().into_iter().map(|r| (r[0], r[1])).collect())
+}
+
+fn from_centroids(centroids: &[(f64, f64)]) -> Array2<f64> {
+    Array2::from_shape_fn((centroids.len(), 2), |(i, j)| {
+        if j == 0 {
+            centroids[i].0
+        } else {
+            centroids[i].1
+        }
+    })
+}
+
+/// Merging t-digest (Dunning) compression of (mean, weight) centroids with the
+/// logistic scale function, so centroids stay small near the tails and the
+/// extremes are singletons. About `compression` / 2 centroids are kept.
+pub fn _tdigest_compress(mut centroids: Vec<(f64, f64)>, compression: f64) -> Vec<(f64, f64)> {
+    if centroids.is_empty() {
+        return centroids;
+    }
+    centroids.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let total: f64 = centroids.iter().map(|c| c.1).sum();
+    // k2 scale function, the normalizer grows slowly with the number of values
+    let normalizer = compression / (4.0 * (total / compression).max(1.0).ln() + 24.0);
+    let scale = |q: f64| normalizer * (q / (1.0 - q)).ln();
+    let inverse = |k: f64| 1.0 / (1.0 + (-k / normalizer).exp());
+
+    let mut compressed = Vec::new();
+    let mut current = centroids[0];
+    let mut weight_so_far = 0.0;
+    let mut limit = total * inverse(scale(0.0) + 1.0);
+    for c in centroids[1..].iter() {
+        if weight_so_far + current.1 + c.1 <= limit {
+            current.1 += c.1;
+            current.0 += (c.0 - current.0) * c.1 / current.1;
+        } else {
+            weight_so_far += current.1;
+            compressed.push(current);
+            current = *c;
+            limit = total * inverse(scale(weight_so_far / total) + 1.0);
+        }
+    }
+    compressed.push(current);
+    compressed
+}
+
+/// Quantiles by linear interpolation between the centroid means placed at the
+/// centre of their weight, using the same positions as `np.quantile` so an
+/// uncompressed digest gives exact results. `min` and `max` pin the tails.
+pub fn _tdigest_quantile(centroids: &[(f64, f64)], min: f64, max: f64, qs: &[f64]) -> Vec<f64> {
+    if centroids.is_empty() {
+        return vec![f64::NAN; qs.len()];
+    }
+    let total: f64 = centroids.iter().map(|c| c.1).sum();
+    let mut knots = vec![(0.0, min)];
+    let mut cumulative = 0.0;
+    for (mean, weight) in centroids.iter() {
+        knots.push((cumulative + (weight - 1.0) / 2.0, *mean));
+        cumulative += weight;
+    }
+    knots.push((total - 1.0, max));
+
+    qs.iter()
+        .map(|q| {
+            let position = q * (total - 1.0);
+            let i = knots
+                .partition_point(|k| k.0 <= position)
+                .clamp(1, knots.len() - 1);
+            let ((x0, y0), (x1, y1)) = (knots[i - 1], knots[i]);
+            if x1 > x0 {
+                y0 + (y1 - y0) * ((position - x0) / (x1 - x0)).clamp(0.0, 1.0)
+            } else {
+                y0
+            }
+        })
+        .collect()
+}
+
+/// Counts per bin like `np.histogram`: bins are half open apart from the last,
+/// which includes its right edge, and values outside the edges are ignored
+pub fn _histogram<F>(arr: ArrayViewD<F>, edges: &[f64]) -> Array1<i64>
+where
+    F: Copy + Into<f64>,
+{
+    let n_bins = edges.len() - 1;
+    let (first, last) = (edges[0], edges[n_bins]);
+    let mut counts = Array1::<i64>::zeros(n_bins);
+    for x in arr.iter() {
+        let x: f64 = (*x).into();
+        // also skips NaN
+        if !(x >= first && x <= last) {
+            continue;
+        }
+        let bin = (edges.partition_point(|e| *e <= x) - 1).min(n_bins - 1);
+        counts[bin] += 1;
+    }
+    counts
+}
@@ -0,0 +1,134 @@
+use ndarray::{Array2, ArrayView1, ArrayView2};
+use numpy::*;
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+/// Number of values per query: DCG, ideal DCG, reciprocal rank, sum of the
+/// precisions at every relevant hit, hits, relevant items and the cutoff
+const REQS_LEN: usize = 7;
+
+/// Ranking computational requirements of every row of a (Q, N) matrix
+#[pyfunction]
+#[pyo3(name = "_ranking_reqs")]
+#[pyo3(text_signature = "(relevance: np.ndarray, scores: np.ndarray, k: Optional[int], /)")]
+pub fn py_ranking_reqs<'a>(
+    py: Python<'a>,
+    relevance: PyReadonlyArray2<f64>,
+    scores: PyReadonlyArray2<f64>,
+    k: Option<usize>,
+) -> PyResult<&'a PyArray2<f64>> {
+    let x = _ranking_reqs(relevance.as_array(), scores.as_array(), k)?;
+    Ok(PyArray2::from_array(py, &x))
+}
+
+/// Ranking computational requirements of the items grouped by query id
+#[pyfunction]
+#[pyo3(name = "_ranking_reqs_grouped")]
+#[pyo3(
+    text_signature = "(relevance: np.ndarray, scores: np.ndarray, query_ids: np.ndarray, k: Optional[int], /)"
+)]
+pub fn py_ranking_reqs_grouped<'a>(
+    py: Python<'a>,
+    relevance: PyReadonlyArray1<f64>,
+    scores: PyReadonlyArray1<f64>,
+    query_ids: PyReadonlyArray1<i64>,
+    k: Option<usize>,
+) -> PyResult<(&'a PyArray1<i64>, &'a PyArray2<f64>)> {
+    let (ids, x) = _ranking_reqs_grouped(
+        relevance.as_array(),
+        scores.as_array(),
+        query_ids.as_array(),
+        k,
+    )?;
+    Ok((PyArray1::from_vec(py, ids), PyArray2::from_array(py, &x)))
+}
+
+/// Sum of gain / log2(rank + 1) over the first `cutoff` gains
+fn dcg<'a>(gains: impl Iterator<Item = &'a f64>, cutoff: usize) -> f64 {
+    gains
+        .take(cutoff)
+        .enumerate()
+        .map(|(i, g)| g / ((i + 2) as f64).log2())
+        .sum()
+}
+
+/// Requirements of one query, items are ranked by descending score and tied
+/// scores keep their input order (NaN scores rank first, as `f64::total_cmp`)
+fn query_reqs(relevance: &[f64], scores: &[f64], k: Option<usize>) -> [f64; REQS_LEN] {
+    let cutoff = k.unwrap_or(relevance.len()).min(relevance.len());
+    let mut order: Vec<usize> = (0..relevance.len()).collect();
+    order.sort_by(|a, b| scores[*b].total_cmp(&scores[*a]));
+    let mut ideal = relevance.to_vec();
+    ideal.sort_by(|a, b| b.total_cmp(a));
+
+    let mut x = [0.0; REQS_LEN];
+    x[0] = dcg(order.iter().map(|i| &relevance[*i]), cutoff);
+    x[1] = dcg(ideal.iter(), cutoff);
+    for (rank, i) in order[..cutoff].iter().enumerate() {
+        if relevance[*i] > 0.0 {
+            x[4] += 1.0;
+            if x[2] == 0.0 {
+                x[2] = 1.0 / (rank + 1) as f64;
+            }
+            x[3] += x[4] / (rank + 1) as f64;
+        }
+    }
+    x[5] = relevance.iter().filter(|r| **r > 0.0).count() as f64;
+    x[6] = cutoff as f64;
+    x
+}
+
+fn to_array(reqs: Vec<[f64; REQS_LEN]>) -> Array2<f64> {
+    Array2::from_shape_fn((reqs.len(), REQS_LEN), |(q, j)| reqs[q][j])
+}
+
+/// (Q, 7) requirements of the rows of (Q, N) relevance and score matrices, the
+/// queries are processed in parallel
+pub fn _ranking_reqs(
+    relevance: ArrayView2<f64>,
+    scores: ArrayView2<f64>,
+    k: Option<usize>,
+) -> PyResult<Array2<f64>> {
+    if relevance.shape() != scores.shape() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "relevance and scores must be same shape",
+        ));
+    }
+    let reqs = (0..relevance.nrows())
+        .into_par_iter()
+        .map(|q| query_reqs(&relevance.row(q).to_vec(), &scores.row(q).to_vec(), k))
+        .collect();
+    Ok(to_array(reqs))
+}
+
+/// Sorted unique query ids and their (G, 7) requirements from flat arrays of
+/// items, the queries are processed in parallel
+pub fn _ranking_reqs_grouped(
+    relevance: ArrayView1<f64>,
+    scores: ArrayView1<f64>,
+    query_ids: ArrayView1<i64>,
+    k: Option<usize>,
+) -> PyResult<(Vec<i64>, Array2<f64>)> {
+    if relevance.len() != scores.len() || relevance.len() != query_ids.len() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "relevance, scores and query_ids must be same shape",
+        ));
+    }
+    // stable so items of a query keep their input order
+    let mut order: Vec<usize> = (0..query_ids.len()).collect();
+    order.sort_by_key(|i| query_ids[*i]);
+    let groups: Vec<&[usize]> = order
+        .chunk_by(|a, b| query_ids[*a] == query_ids[*b])
+        .collect();
+
+    let ids = groups.iter().map(|g| query_ids[g[0]]).collect();
+    let reqs = groups
+        .par_iter()
+        .map(|g| {
+            let rel: Vec<f64> = g.iter().map(|i| relevance[*i]).collect();
+            let sc: Vec<f64> = g.iter().map(|i| scores[*i]).collect();
+            query_reqs(&rel, &sc, k)
+        })
+        .collect();
+    Ok((ids, to_array(reqs)))
+}
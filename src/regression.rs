@@ -0,0 +1,137 @@
+use ndarray::{Array2, ArrayView1, ArrayView2};
+use numpy::*;
+use pyo3::prelude::*;
+use std::iter::zip;
+
+/// Regression computational requirements per output column
+#[pyfunction]
+#[pyo3(name = "_regression_reqs")]
+#[pyo3(text_signature = "(actual: np.ndarray, pred: np.ndarray, weights: Optional[np.ndarray], /)")]
+pub fn py_regression_reqs<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    pred: &'a PyAny,
+    weights: Option<PyReadonlyArray1<f64>>,
+) -> PyResult<&'a PyArray2<f64>> {
+    let weights = weights.as_ref().map(|w| w.as_array());
+    if let (Ok(a), Ok(p)) = (
+        actual.extract::<PyReadonlyArray2<f32>>(),
+        pred.extract::<PyReadonlyArray2<f32>>(),
+    ) {
+        let x = _regression_reqs(a.as_array(), p.as_array(), weights)?;
+        return Ok(PyArray2::from_array(py, &x));
+    }
+    if let (Ok(a), Ok(p)) = (
+        actual.extract::<PyReadonlyArray2<f64>>(),
+        pred.extract::<PyReadonlyArray2<f64>>(),
+    ) {
+        let x = _regression_reqs(a.as_array(), p.as_array(), weights)?;
+        return Ok(PyArray2::from_array(py, &x));
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        "Unsupported numpy dtype",
+    ))
+}
+
+/// Median of `values` like `np.median`, or the lowest value whose cumulative
+/// weight reaches half of the total weight when weighted, NaN if any value is
+fn median(values: Vec<f64>, weights: Option<&[f64]>) -> f64 {
+    if values.is_empty() || values.iter().any(|v| v.is_nan()) {
+        return f64::NAN;
+    }
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|x, y| values[*x].total_cmp(&values[*y]));
+    match weights {
+        None => {
+            let n = order.len();
+            if n % 2 == 1 {
+                values[order[n / 2]]
+            } else {
+                (values[order[n / 2 - 1]] + values[order[n / 2]]) / 2.0
+            }
+        }
+        Some(w) => {
+            let half = w.iter().sum::<f64>() / 2.0;
+            let mut cumulative = 0.0;
+            for i in order.iter() {
+                cumulative += w[*i];
+                if cumulative >= half {
+                    return values[*i];
+                }
+            }
+            values[order[order.len() - 1]]
+        }
+    }
+}
+
+/// Sum of weights, weighted sums of |e|, e^2, |e| / |y|, of the squares of y and
+/// of e around their weighted means and the (weighted) median |e| of an output
+/// column where e = y - pred
+fn column_reqs<F>(
+    actual: ArrayView1<F>,
+    pred: ArrayView1<F>,
+    weights: &[f64],
+    weighted: bool,
+) -> [f64; 7]
+where
+    F: Copy + Into<f64>,
+{
+    let errors: Vec<f64> = zip(actual.iter(), pred.iter())
+        .map(|(a, p)| (*a).into() - (*p).into())
+        .collect();
+    let sw: f64 = weights.iter().sum();
+    let (mut y_mean, mut e_mean) = (0.0, 0.0);
+    for ((a, e), w) in zip(zip(actual.iter(), errors.iter()), weights.iter()) {
+        y_mean += w * (*a).into();
+        e_mean += w * e;
+    }
+    y_mean /= sw;
+    e_mean /= sw;
+
+    let mut x = [sw, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+    for ((a, e), w) in zip(zip(actual.iter(), errors.iter()), weights.iter()) {
+        let y: f64 = (*a).into();
+        x[1] += w * e.abs();
+        x[2] += w * e * e;
+        x[3] += w * e.abs() / y.abs().max(f64::EPSILON);
+        x[4] += w * (y - y_mean) * (y - y_mean);
+        x[5] += w * (e - e_mean) * (e - e_mean);
+    }
+    let abs_errors = errors.iter().map(|e| e.abs()).collect();
+    x[6] = median(abs_errors, if weighted { Some(weights) } else { None });
+    x
+}
+
+pub fn _regression_reqs<F>(
+    actual: ArrayView2<F>,
+    pred: ArrayView2<F>,
+    weights: Option<ArrayView1<f64>>,
+) -> PyResult<Array2<f64>>
+where
+    F: Copy + Into<f64>,
+{
+    if actual.shape() != pred.shape() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "actual and pred must be same shape",
+        ));
+    }
+    let n_samples = actual.shape()[0];
+    let w: Vec<f64> = match weights {
+        Some(w) if w.len() != n_samples => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "weights must have one entry per sample",
+            ))
+        }
+        Some(w) => w.to_vec(),
+        None => vec![1.0; n_samples],
+    };
+
+    let mut reqs = Array2::<f64>::zeros((actual.shape()[1], 7));
+    for (mut row, (a, p)) in zip(reqs.rows_mut(), zip(actual.columns(), pred.columns())) {
+        let x = column_reqs(a, p, &w, weights.is_some());
+        for (r, v) in zip(row.iter_mut(), x.iter()) {
+            *r = *v;
+        }
+    }
+    Ok(reqs)
+}
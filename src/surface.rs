@@ -0,0 +1,81 @@
+use ndarray::{ArrayD, Zip};
+use numpy::*;
+use pyo3::prelude::*;
+
+use crate::boundary::{_boundary, _squared_distance_transform};
+use crate::numpy_dispatch_bool;
+
+/// Surface distances between the boundaries of two binary masks
+#[pyfunction]
+#[pyo3(name = "_surface_distances")]
+#[pyo3(text_signature = "(actual: np.ndarray, pred: np.ndarray, spacing: List[float], /)")]
+pub fn py_surface_distances<'a>(
+    py: Python<'a>,
+    actual: &'a PyAny,
+    pred: &'a PyAny,
+    spacing: Vec<f64>,
+) -> PyResult<(&'a PyArray1<f64>, &'a PyArray1<f64>)> {
+    numpy_dispatch_bool!(
+        py,
+        surface_distances,
+        PyResult<(&'a PyArray1<f64>, &'a PyArray1<f64>)>,
+        actual,
+        pred;
+        &spacing
+    )
+}
+
+/// Euclidean distance from every `points` element to the nearest `target` element
+fn directed_distances(points: &ArrayD<bool>, target: &ArrayD<bool>, spacing: &[f64]) -> Vec<f64> {
+    let dist = _squared_distance_transform(target, spacing);
+    Zip::from(points)
+        .and(&dist)
+        .fold(Vec::new(), |mut acc, &p, &d| {
+            if p {
+                acc.push(d.sqrt());
+            }
+            acc
+        })
+}
+
+pub fn _surface_distances<T>(
+    actual: PyReadonlyArrayDyn<T>,
+    pred: PyReadonlyArrayDyn<T>,
+    spacing: &[f64],
+) -> PyResult<(Vec<f64>, Vec<f64>)>
+where
+    T: Copy + numpy::Element + Into<i128>,
+{
+    if actual.shape() != pred.shape() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "actual and pred must be same shape",
+        ));
+    }
+    if spacing.len() != actual.ndim() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "spacing must have one entry per array dimension",
+        ));
+    }
+    let actual = _boundary(&actual.as_array().mapv(|x| x.into() != 0));
+    let pred = _boundary(&pred.as_array().mapv(|x| x.into() != 0));
+    Ok((
+        directed_distances(&pred, &actual, spacing),
+        directed_distances(&actual, &pred, spacing),
+    ))
+}
+
+fn surface_distances<'a, T>(
+    py: Python<'a>,
+    actual: PyReadonlyArrayDyn<T>,
+    pred: PyReadonlyArrayDyn<T>,
+    spacing: &[f64],
+) -> PyResult<(&'a PyArray1<f64>, &'a PyArray1<f64>)>
+where
+    T: Copy + numpy::Element + Into<i128>,
+{
+    let (pred_to_actual, actual_to_pred) = _surface_distances(actual, pred, spacing)?;
+    Ok((
+        PyArray1::from_vec(py, pred_to_actual),
+        PyArray1::from_vec(py, actual_to_pred),
+    ))
+}
@@ -0,0 +1,57 @@
+use numpy::*;
+use pyo3::prelude::*;
+use std::iter::zip;
+
+/// Top-k computational requirements
+#[pyfunction]
+#[pyo3(name = "_top_k_reqs")]
+#[pyo3(text_signature = "(indices: np.ndarray, scores: np.ndarray, k: int, /)")]
+pub fn py_top_k_reqs<'a>(
+    py: Python<'a>,
+    indices: PyReadonlyArray1<i64>,
+    scores: &'a PyAny,
+    k: usize,
+) -> PyResult<&'a PyArray2<i64>> {
+    if let Ok(s) = scores.extract::<PyReadonlyArray2<f32>>() {
+        return top_k_reqs(py, indices, s, k);
+    }
+    if let Ok(s) = scores.extract::<PyReadonlyArray2<f64>>() {
+        return top_k_reqs(py, indices, s, k);
+    }
+    Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+        "Unsupported numpy dtype",
+    ))
+}
+
+/// Per-class top-k hits and support. A row is a hit when fewer than k scores are
+/// strictly greater than the true class score, so no row is ever sorted and the
+/// scan stops as soon as k greater scores are seen (ties favour the true class).
+fn top_k_reqs<'a, F>(
+    py: Python<'a>,
+    indices: PyReadonlyArray1<i64>,
+    scores: PyReadonlyArray2<F>,
+    k: usize,
+) -> PyResult<&'a PyArray2<i64>>
+where
+    F: Copy + numpy::Element + PartialOrd,
+{
+    let scores = scores.as_array();
+    let n_classes = scores.shape()[1];
+    let mut ret = ndarray::Array2::<i64>::zeros((n_classes, 2));
+    for (idx, row) in zip(indices.as_array().iter(), scores.rows()) {
+        // -1 marks a y_true value that is not one of the labels
+        if *idx < 0 || *idx as usize >= n_classes {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "y_true values must be one of the labels",
+            ));
+        }
+        let idx = *idx as usize;
+        let target = row[idx];
+        // NaN scores can never be ranked so they are always a miss
+        let hit = target.partial_cmp(&target).is_some()
+            && row.iter().filter(|s| **s > target).take(k).count() < k;
+        ret[(idx, 0)] += hit as i64;
+        ret[(idx, 1)] += 1;
+    }
+    Ok(PyArray2::from_array(py, &ret))
+}
@@ -1,6 +1,6 @@
 use numpy::*;
 use pyo3::{prelude::*, types::PySet};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::numpy_dispatch_bool;
 
@@ -27,3 +27,66 @@ where
     }
     PySet::new(py, ret.as_slice())
 }
+
+/// label indices
+#[pyfunction]
+#[pyo3(name = "_label_indices")]
+#[pyo3(text_signature = "(arr: np.ndarray, labels: Union[List, np.ndarray], /)")]
+pub fn py_label_indices<'a>(
+    py: Python<'a>,
+    arr: &'a PyAny,
+    labels: &'a PyAny,
+) -> PyResult<&'a PyArrayDyn<i64>> {
+    numpy_dispatch_bool!(
+        py,
+        label_indices,
+        PyResult<&'a PyArrayDyn<i64>>,
+        arr,
+        labels
+    )
+}
+
+/// ndarray of each value's position in labels (-1 if not a label)
+fn label_indices<'a, T>(
+    py: Python<'a>,
+    arr: numpy::PyReadonlyArrayDyn<T>,
+    labels: numpy::PyReadonlyArrayDyn<T>,
+) -> PyResult<&'a PyArrayDyn<i64>>
+where
+    T: Clone + numpy::Element + std::hash::Hash + std::cmp::Eq,
+{
+    let idx_map: HashMap<T, i64> = HashMap::from_iter(
+        labels
+            .as_array()
+            .iter()
+            .enumerate()
+            .map(|(x, y)| (y.clone(), x as i64)),
+    );
+    let ret = arr
+        .as_array()
+        .map(|x| idx_map.get(x).copied().unwrap_or(-1));
+    Ok(PyArrayDyn::from_array(py, &ret))
+}
+
+/// Number of consecutive values (in logical order) that share each entry of
+/// `groups`, after checking the group indices are below `n_groups`
+pub fn values_per_group(
+    groups: ndarray::ArrayView1<i64>,
+    n_values: usize,
+    n_groups: usize,
+) -> PyResult<usize> {
+    if groups.is_empty() && n_values == 0 {
+        return Ok(1);
+    }
+    if groups.is_empty() || !n_values.is_multiple_of(groups.len()) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "groups must match the leading axes of the values",
+        ));
+    }
+    if groups.iter().any(|g| *g < 0 || *g as usize >= n_groups) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "group indices must be in [0, n_groups)",
+        ));
+    }
+    Ok(n_values / groups.len())
+}
@@ -3,6 +3,7 @@ import pytest
 from dictdiffer import diff
 
 import fast_stats
+from fast_stats.exceptions import ShapeError
 
 
 @pytest.mark.parametrize(
@@ -151,6 +152,72 @@ def test_f1(y_true, y_pred, zero_division, expected):
         )
 
 
+@pytest.mark.parametrize(
+    "y_true,y_pred,beta,zero_division,expected",
+    [
+        (
+            np.ones(4, dtype=np.uint64),
+            np.zeros(4, dtype=np.uint64),
+            2.0,
+            "zero",
+            0.0,
+        ),  # all FN
+        (
+            np.ones(4, dtype=np.uint64),
+            np.ones(4, dtype=np.uint64),
+            0.5,
+            "zero",
+            1.0,
+        ),  # all TP
+        (
+            np.zeros(4, dtype=np.uint64),
+            np.zeros(4, dtype=np.uint64),
+            2.0,
+            "none",
+            None,
+        ),  # No TP & No FP, & No FN
+        (
+            np.ones(4, dtype=np.uint64),
+            np.array([1, 0, 0, 0], dtype=np.uint64),
+            2.0,
+            "none",
+            5 * (1 / 4 * 1.0) / (4 * 1.0 + 1 / 4),
+        ),  # 1 TP & 3 FN
+        (
+            np.ones(4, dtype=np.uint64),
+            np.array([1, 0, 0, 0], dtype=np.uint64),
+            0.0,
+            "none",
+            1.0,
+        ),  # beta of 0 is precision
+        (
+            np.array([1, 1, 0, 0], dtype=np.uint64),
+            np.array([0, 1, 1, 0], dtype=np.uint64),
+            1.0,
+            "none",
+            0.5,
+        ),  # beta of 1 is f1
+    ],
+)
+def test_fbeta(y_true, y_pred, beta, zero_division, expected):
+    if expected is None:
+        assert (
+            fast_stats.binary_fbeta_score(y_true, y_pred, beta, zero_division) is None
+        )
+    else:
+        assert np.allclose(
+            fast_stats.binary_fbeta_score(y_true, y_pred, beta, zero_division),
+            expected,
+        )
+
+
+def test_fbeta_negative_beta():
+    with pytest.raises(ValueError):
+        fast_stats.binary_fbeta_score(
+            np.ones(4, dtype=np.uint64), np.ones(4, dtype=np.uint64), -1.0
+        )
+
+
 @pytest.mark.parametrize(
     "y_true,y_pred,expected",
     [
@@ -227,3 +294,37 @@ def test_stats(y_true, y_pred, zero_division, expected):
         )
         == 0
     )
+
+
+@pytest.mark.parametrize("ci", ["wilson", "clopper_pearson", "agresti_coull"])
+def test_stats_ci(ci):
+    y_true = np.array([1, 1, 1, 1, 1, 0, 0, 0, 0, 0])
+    y_pred = np.array([1, 1, 1, 0, 0, 1, 1, 0, 0, 0])
+    res = fast_stats.binary_stats(y_true, y_pred, ci=ci, confidence=0.9)
+    assert res["accuracy"] == 0.6
+    assert np.allclose(res["precision_ci"], fast_stats.proportion_ci(3, 5, ci, 0.9))
+    assert np.allclose(res["recall_ci"], fast_stats.proportion_ci(3, 5, ci, 0.9))
+    assert np.allclose(res["accuracy_ci"], fast_stats.proportion_ci(6, 10, ci, 0.9))
+    assert "accuracy" not in fast_stats.binary_stats(y_true, y_pred)
+
+
+@pytest.mark.parametrize("ci", [None, "wilson"])
+def test_stats_groups(ci):
+    y_true = np.array([[1, 1, 0], [0, 0, 0], [1, 0, 1], [1, 1, 1]], dtype=bool)
+    y_pred = np.array([[1, 0, 0], [1, 0, 0], [1, 1, 1], [0, 0, 0]], dtype=bool)
+    groups = np.array([5, 2, 5, 9])
+    res = fast_stats.binary_stats(y_true, y_pred, ci=ci, groups=groups)
+    assert list(res) == [2, 5, 9]
+    for g, stats in res.items():
+        mask = groups == g
+        expected = fast_stats.binary_stats(y_true[mask], y_pred[mask], ci=ci)
+        # groups 2 and 9 have a zero denominator, so compare NaN bounds as equal
+        assert stats.keys() == expected.keys()
+        for key, value in expected.items():
+            if value is None:
+                assert stats[key] is None
+            else:
+                assert np.allclose(stats[key], value, equal_nan=True)
+
+    with pytest.raises(ShapeError):
+        fast_stats.binary_stats(y_true, y_pred, groups=np.array([0, 1]))
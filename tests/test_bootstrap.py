@@ -0,0 +1,99 @@
+import numpy as np
+import pytest
+
+import fast_stats
+from fast_stats.exceptions import ShapeError
+
+Y_TRUE = np.array([1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 1, 0, 0, 1, 1, 0, 1, 0, 1, 1])
+Y_PRED = np.array([1, 1, 0, 1, 0, 1, 0, 1, 0, 0, 1, 0, 1, 1, 1, 0, 1, 0, 0, 1])
+MC_TRUE = np.array([0, 1, 2, 2, 1, 0, 2, 1, 0, 2, 1, 1])
+MC_PRED = np.array([0, 2, 2, 2, 1, 0, 1, 1, 0, 2, 0, 1])
+
+
+@pytest.mark.parametrize(
+    "metric,expected",
+    [("precision", 9 / 11), ("recall", 9 / 12), ("f1", 18 / 23), ("iou", 9 / 14)],
+)
+@pytest.mark.parametrize("method", ["percentile", "bca"])
+def test_binary_bootstrap_ci(metric, expected, method):
+    res = fast_stats.binary_bootstrap_ci(
+        Y_TRUE, Y_PRED, metric, n_boot=500, method=method, seed=3
+    )
+    assert np.isclose(res["estimate"], expected)
+    assert 0.0 <= res["low"] <= res["estimate"] <= res["high"] <= 1.0
+    assert res["low"] < res["high"]
+
+
+def test_binary_bootstrap_ci_seed():
+    a = fast_stats.binary_bootstrap_ci(Y_TRUE, Y_PRED, n_boot=200, seed=1)
+    b = fast_stats.binary_bootstrap_ci(Y_TRUE, Y_PRED, n_boot=200, seed=1)
+    c = fast_stats.binary_bootstrap_ci(Y_TRUE, Y_PRED, n_boot=200, seed=2)
+    assert a == b
+    assert a != c
+
+
+def test_binary_bootstrap_ci_narrows_with_samples():
+    small = fast_stats.binary_bootstrap_ci(Y_TRUE, Y_PRED, n_boot=500)
+    y_true, y_pred = np.tile(Y_TRUE, 10_000), np.tile(Y_PRED, 10_000)
+    large = fast_stats.binary_bootstrap_ci(y_true, y_pred, n_boot=500)
+    assert np.isclose(large["estimate"], small["estimate"])
+    assert large["high"] - large["low"] < (small["high"] - small["low"]) / 50
+
+
+def test_binary_bootstrap_ci_groups():
+    y_true = Y_TRUE.reshape(5, 4).astype(bool)
+    y_pred = Y_PRED.reshape(5, 4).astype(bool)
+    groups = np.array([7, 7, 3, 3, 9])
+    res = fast_stats.binary_bootstrap_ci(y_true, y_pred, groups=groups, seed=5)
+    assert np.isclose(res["estimate"], 18 / 23)
+    assert res["low"] <= res["estimate"] <= res["high"]
+
+    # every group is perfect so every replicate is too
+    res = fast_stats.binary_bootstrap_ci(y_true, y_true, groups=groups)
+    assert res == {"estimate": 1.0, "low": 1.0, "high": 1.0}
+
+
+def test_binary_bootstrap_ci_zero_division():
+    y = np.zeros(10, dtype=int)
+    res = fast_stats.binary_bootstrap_ci(y, y, n_boot=50)
+    assert all(np.isnan(v) for v in res.values())
+    res = fast_stats.binary_bootstrap_ci(y, y, n_boot=50, zero_division="zero")
+    assert res == {"estimate": 0.0, "low": 0.0, "high": 0.0}
+
+
+@pytest.mark.parametrize("average", ["micro", "macro", "none"])
+@pytest.mark.parametrize("method", ["percentile", "bca"])
+def test_bootstrap_ci(average, method):
+    res = fast_stats.bootstrap_ci(
+        MC_TRUE, MC_PRED, "precision", average=average, method=method, seed=11
+    )
+    expected = fast_stats.precision(MC_TRUE, MC_PRED, average=average)
+    assert np.allclose(res["estimate"], expected)
+    assert np.all(res["low"] <= res["estimate"])
+    assert np.all(res["estimate"] <= res["high"])
+
+
+def test_bootstrap_ci_groups():
+    groups = np.repeat(np.arange(4), 3)
+    res = fast_stats.bootstrap_ci(
+        MC_TRUE, MC_PRED, "iou", average="none", groups=groups, n_boot=300
+    )
+    expected = fast_stats.jaccard_score(MC_TRUE, MC_PRED, average="none")
+    assert np.allclose(res["estimate"], expected)
+    assert res["low"].shape == res["high"].shape == (3,)
+    assert np.all(res["low"] <= res["high"])
+
+
+def test_bootstrap_ci_exceptions():
+    with pytest.raises(TypeError):
+        fast_stats.bootstrap_ci(MC_TRUE.tolist(), MC_PRED)
+    with pytest.raises(ShapeError):
+        fast_stats.bootstrap_ci(MC_TRUE, MC_PRED[:-1])
+    with pytest.raises(ShapeError):
+        fast_stats.bootstrap_ci(MC_TRUE, MC_PRED, groups=np.arange(5))
+    with pytest.raises(ValueError):
+        fast_stats.binary_bootstrap_ci(Y_TRUE, Y_PRED, n_boot=0)
+    with pytest.raises(ValueError):
+        fast_stats.binary_bootstrap_ci(Y_TRUE, Y_PRED, confidence=1.0)
+    with pytest.raises(ValueError):
+        fast_stats.binary_bootstrap_ci(Y_TRUE, Y_PRED, method="normal")
@@ -0,0 +1,57 @@
+import numpy as np
+import pytest
+
+import fast_stats
+
+
+def _square(rows, cols, shape=(6, 6)):
+    mask = np.zeros(shape, dtype=np.uint8)
+    mask[rows[0] : rows[1], cols[0] : cols[1]] = 1
+    return mask
+
+
+Y_TRUE = _square((1, 5), (1, 5))
+Y_PRED = _square((1, 5), (2, 6))
+CUBE = np.ones((3, 3, 3), dtype=bool)
+EMPTY = np.zeros((4, 4), dtype=bool)
+
+
+def test_boundary_mask():
+    assert fast_stats.boundary_mask(Y_TRUE).sum() == 12
+    assert not fast_stats.boundary_mask(Y_TRUE)[2, 2]
+    assert fast_stats.boundary_mask(np.ones((3, 3, 3), dtype=bool)).sum() == 26
+
+
+@pytest.mark.parametrize(
+    "y_true,y_pred,tolerance_px,zero_division,expected",
+    [
+        (Y_TRUE, Y_PRED, 0, "none", 0.5),
+        (Y_TRUE, Y_PRED, 1, "none", 1.0),
+        (CUBE, CUBE, 0, "none", 1.0),
+        (EMPTY, EMPTY, 1, "zero", 0.0),
+        (EMPTY, EMPTY, 1, "none", None),
+    ],
+)
+def test_boundary_f1(y_true, y_pred, tolerance_px, zero_division, expected):
+    res = fast_stats.boundary_f1(y_true, y_pred, tolerance_px, zero_division)
+    if expected is None:
+        assert res is None
+    else:
+        assert np.isclose(res, expected)
+
+
+@pytest.mark.parametrize(
+    "y_true,y_pred,dilation_px,zero_division,expected",
+    [
+        (Y_TRUE, Y_PRED, 1, "none", 1 / 3),
+        (Y_TRUE, Y_PRED, 2, "none", 0.6),
+        (EMPTY, EMPTY, 1, "zero", 0.0),
+        (EMPTY, EMPTY, 1, "none", None),
+    ],
+)
+def test_boundary_iou(y_true, y_pred, dilation_px, zero_division, expected):
+    res = fast_stats.boundary_iou(y_true, y_pred, dilation_px, zero_division)
+    if expected is None:
+        assert res is None
+    else:
+        assert np.isclose(res, expected)
@@ -0,0 +1,50 @@
+import numpy as np
+import pytest
+
+import fast_stats
+from fast_stats.exceptions import ShapeError
+
+BOXES_A = np.array([[0, 0, 2, 2]])
+BOXES_B = np.array([[1, 1, 3, 3], [0, 0, 4, 2], [5, 5, 6, 6]])
+
+
+@pytest.mark.parametrize(
+    "func,expected",
+    [
+        (fast_stats.box_iou, [[1 / 7, 0.5, 0.0]]),
+        (fast_stats.generalized_box_iou, [[-5 / 63, 0.5, -31 / 36]]),
+        (fast_stats.distance_box_iou, [[2 / 63, 0.45, -(2 * 4.5**2) / 72]]),
+        (
+            fast_stats.complete_box_iou,
+            [[2 / 63, 0.446751870701443, -(2 * 4.5**2) / 72]],
+        ),
+    ],
+)
+def test_box_iou_kinds(func, expected):
+    assert np.allclose(func(BOXES_A, BOXES_B), expected)
+
+
+@pytest.mark.parametrize(
+    "boxes_a,boxes_b,box_format",
+    [
+        (BOXES_A, BOXES_B, "xyxy"),
+        (np.array([[0, 0, 2, 2]]), np.array([[1, 1, 2, 2], [0, 0, 4, 2]]), "xywh"),
+        (
+            np.array([[1.0, 1.0, 2.0, 2.0]], dtype=np.float32),
+            np.array([[2.0, 2.0, 2.0, 2.0], [2.0, 1.0, 4.0, 2.0]], dtype=np.float32),
+            "cxcywh",
+        ),
+    ],
+)
+def test_box_iou_formats(boxes_a, boxes_b, box_format):
+    res = fast_stats.box_iou(boxes_a, boxes_b, box_format)
+    assert np.allclose(res[:, :2], [[1 / 7, 0.5]])
+
+
+def test_box_iou_empty():
+    assert fast_stats.box_iou(np.zeros((0, 4)), BOXES_B).shape == (0, 3)
+
+
+def test_box_iou_shape():
+    with pytest.raises(ShapeError):
+        fast_stats.box_iou(np.zeros((2, 5)), BOXES_B)
@@ -0,0 +1,95 @@
+import numpy as np
+import pytest
+from dictdiffer import diff
+
+from fast_stats import classification_report
+
+REPORT = (
+    "              precision    recall  f1-score   support\n"
+    "\n"
+    "     class 0       0.50      1.00      0.67         1\n"
+    "     class 1       0.00      0.00      0.00         1\n"
+    "     class 2       1.00      0.67      0.80         3\n"
+    "\n"
+    "    accuracy                           0.60         5\n"
+    "   macro avg       0.50      0.56      0.49         5\n"
+    "weighted avg       0.70      0.60      0.61         5\n"
+)
+
+
+def test_classification_report_text():
+    y_true = np.array([0, 1, 2, 2, 2])
+    y_pred = np.array([0, 0, 2, 2, 1])
+    assert (
+        classification_report(
+            y_true, y_pred, target_names=["class 0", "class 1", "class 2"]
+        )
+        == REPORT
+    )
+
+
+@pytest.mark.parametrize(
+    "y_true,y_pred,kwargs,expected",
+    [
+        (
+            np.array([0, 1, 2, 2, 2]),
+            np.array([0, 0, 2, 2, 1]),
+            {},
+            {
+                "0": {"precision": 0.5, "recall": 1.0, "f1-score": 2 / 3, "support": 1},
+                "1": {"precision": 0.0, "recall": 0.0, "f1-score": 0.0, "support": 1},
+                "2": {"precision": 1.0, "recall": 2 / 3, "f1-score": 0.8, "support": 3},
+                "accuracy": 0.6,
+                "macro avg": {
+                    "precision": 0.5,
+                    "recall": 5 / 9,
+                    "f1-score": (2 / 3 + 0.8) / 3,
+                    "support": 5,
+                },
+                "weighted avg": {
+                    "precision": 0.7,
+                    "recall": 0.6,
+                    "f1-score": (2 / 3 + 2.4) / 5,
+                    "support": 5,
+                },
+            },
+        ),  # all labels
+        (
+            np.array([0, 1, 2, 2, 2]),
+            np.array([0, 0, 2, 2, 1]),
+            {"labels": [0, 2]},
+            {
+                "0": {"precision": 0.5, "recall": 1.0, "f1-score": 2 / 3, "support": 1},
+                "2": {"precision": 1.0, "recall": 2 / 3, "f1-score": 0.8, "support": 3},
+                "micro avg": {
+                    "precision": 0.75,
+                    "recall": 0.75,
+                    "f1-score": 0.75,
+                    "support": 4,
+                },
+                "macro avg": {
+                    "precision": 0.75,
+                    "recall": 5 / 6,
+                    "f1-score": (2 / 3 + 0.8) / 2,
+                    "support": 4,
+                },
+                "weighted avg": {
+                    "precision": 3.5 / 4,
+                    "recall": 3 / 4,
+                    "f1-score": (2 / 3 + 2.4) / 4,
+                    "support": 4,
+                },
+            },
+        ),  # subset of labels
+    ],
+)
+def test_classification_report_dict(y_true, y_pred, kwargs, expected):
+    report = classification_report(y_true, y_pred, output_dict=True, **kwargs)
+    assert len(list(diff(report, expected, tolerance=1e-9))) == 0
+
+
+def test_classification_report_target_names():
+    with pytest.raises(ValueError):
+        classification_report(
+            np.array([0, 1]), np.array([0, 1]), target_names=["only one"]
+        )
@@ -2,6 +2,7 @@ import numpy as np
 import pytest
 
 from fast_stats import confusion_matrix
+from fast_stats.exceptions import ShapeError
 
 
 @pytest.mark.parametrize(
@@ -57,3 +58,68 @@ from fast_stats import confusion_matrix
 )
 def test_confusion_matrix(y_true, y_pred, labels, expected):
     assert np.allclose(confusion_matrix(y_true, y_pred, labels), expected)
+
+
+SCORES = np.array(
+    [
+        [[0.9, 0.1], [0.05, 0.2], [0.05, 0.7]],
+        [[0.1, 0.3], [0.1, 0.6], [0.8, 0.1]],
+    ]
+)
+
+
+@pytest.mark.parametrize(
+    "y_true,scores,kwargs,expected",
+    [
+        (
+            np.array([[0, 1], [2, 1]]),
+            SCORES,
+            {"class_axis": 1},
+            np.array([[1, 0, 0], [0, 1, 1], [0, 0, 1]]),
+        ),  # (B, C, W)
+        (
+            np.array([[0, 1], [2, 1]], dtype=np.uint8),
+            np.moveaxis(SCORES, 1, -1).astype(np.float32),
+            {"class_axis": -1},
+            np.array([[1, 0, 0], [0, 1, 1], [0, 0, 1]]),
+        ),  # channels last
+        (
+            np.array([[10, 11], [12, 11]]),
+            SCORES,
+            {"class_axis": 1, "labels": [10, 11, 12]},
+            np.array([[1, 0, 0], [0, 1, 1], [0, 0, 1]]),
+        ),  # labels for channels
+    ],
+)
+def test_confusion_matrix_scores(y_true, scores, kwargs, expected):
+    assert np.array_equal(confusion_matrix(y_true, scores, **kwargs), expected)
+
+
+def test_confusion_matrix_scores_shape():
+    with pytest.raises(ShapeError):
+        confusion_matrix(np.array([0, 1, 2]), SCORES, class_axis=1)
+    with pytest.raises(ValueError):
+        confusion_matrix(np.array([[0, 1], [2, 1]]), SCORES, class_axis=3)
+
+
+def test_confusion_matrix_groups():
+    y_true = np.array([[0, 1, 2], [1, 1, 0], [2, 0, 0], [2, 2, 2]])
+    y_pred = np.array([[0, 2, 2], [1, 0, 0], [2, 2, 0], [1, 2, 2]])
+    groups = np.array([3, 1, 3, 1])
+    cm = confusion_matrix(y_true, y_pred, groups=groups)
+    assert cm.shape == (2, 3, 3)
+    for i, g in enumerate([1, 3]):
+        mask = groups == g
+        expected = confusion_matrix(y_true[mask], y_pred[mask], labels=[0, 1, 2])
+        assert np.array_equal(cm[i], expected)
+    assert np.array_equal(cm.sum(axis=0), confusion_matrix(y_true, y_pred))
+
+    # groups of single values
+    cm = confusion_matrix(y_true, y_pred, groups=np.arange(12).reshape(4, 3))
+    assert cm.shape == (12, 3, 3)
+    assert np.all(cm.sum(axis=(1, 2)) == 1)
+
+    with pytest.raises(ShapeError):
+        confusion_matrix(y_true, y_pred, groups=np.array([0, 1, 2]))
+    with pytest.raises(TypeError):
+        confusion_matrix(y_true, y_pred, groups=[0, 1, 2, 3])
@@ -0,0 +1,84 @@
+import numpy as np
+import pytest
+
+import fast_stats
+from fast_stats.exceptions import ShapeError
+
+# 5 both correct, 10 only A correct, 3 only B correct and 2 both wrong
+Y_TRUE = np.ones(20, dtype=int)
+Y_PRED_A = np.array([1] * 15 + [0] * 3 + [0] * 2)
+Y_PRED_B = np.array([1] * 5 + [0] * 10 + [1] * 3 + [0] * 2)
+
+RNG = np.random.default_rng(0)
+MC_TRUE = RNG.integers(0, 3, 300)
+MC_PRED_A = np.where(RNG.random(300) < 0.9, MC_TRUE, RNG.integers(0, 3, 300))
+MC_PRED_B = np.where(RNG.random(300) < 0.5, MC_TRUE, RNG.integers(0, 3, 300))
+
+
+@pytest.mark.parametrize(
+    "exact,statistic,p_value",
+    [
+        (None, 3.0, 0.09228515625),
+        (True, 3.0, 0.09228515625),
+        (False, 36 / 13, 0.09609232),
+    ],
+)
+def test_mcnemar_test(exact, statistic, p_value):
+    res = fast_stats.mcnemar_test(Y_TRUE, Y_PRED_A, Y_PRED_B, exact=exact)
+    assert np.isclose(res["statistic"], statistic)
+    assert np.isclose(res["p_value"], p_value)
+    assert np.isclose(res["effect_size"], 7 / 20)
+
+
+def test_mcnemar_test_no_discordance():
+    res = fast_stats.mcnemar_test(MC_TRUE, MC_PRED_A, MC_PRED_A)
+    assert res == {"statistic": 0.0, "p_value": 1.0, "effect_size": 0.0}
+
+
+@pytest.mark.parametrize(
+    "test", [fast_stats.paired_permutation_test, fast_stats.paired_bootstrap_test]
+)
+@pytest.mark.parametrize("metric", ["precision", "recall", "f1", "iou"])
+def test_paired_tests(test, metric):
+    res = test(MC_TRUE, MC_PRED_A, MC_PRED_B, metric, seed=1)
+    assert res["effect_size"] > 0.1
+    assert res["statistic"] > 3
+    assert res["p_value"] < 0.01
+
+    res = test(MC_TRUE, MC_PRED_A, MC_PRED_A, metric, seed=1)
+    assert res["effect_size"] == 0.0
+    assert res["p_value"] == 1.0
+
+
+@pytest.mark.parametrize(
+    "test", [fast_stats.paired_permutation_test, fast_stats.paired_bootstrap_test]
+)
+def test_paired_tests_options(test):
+    a = test(MC_TRUE, MC_PRED_A, MC_PRED_B, "iou", average="none", seed=2)
+    b = test(MC_TRUE, MC_PRED_A, MC_PRED_B, "iou", average="none", seed=2)
+    assert a["p_value"].shape == a["effect_size"].shape == (3,)
+    assert np.array_equal(a["p_value"], b["p_value"])
+    iou_a = fast_stats.jaccard_score(MC_TRUE, MC_PRED_A)
+    iou_b = fast_stats.jaccard_score(MC_TRUE, MC_PRED_B)
+    assert np.allclose(a["effect_size"], iou_a - iou_b)
+
+    y_true, y_pred_a, y_pred_b = MC_TRUE == 1, MC_PRED_A == 1, MC_PRED_B == 1
+    res = test(y_true, y_pred_a, y_pred_b, pos_label=True)
+    f1_a = fast_stats.binary_f1_score(y_true, y_pred_a)
+    f1_b = fast_stats.binary_f1_score(y_true, y_pred_b)
+    assert np.isclose(res["effect_size"], f1_a - f1_b)
+
+
+def test_compare_exceptions():
+    with pytest.raises(TypeError):
+        fast_stats.mcnemar_test(Y_TRUE.tolist(), Y_PRED_A, Y_PRED_B)
+    with pytest.raises(ShapeError):
+        fast_stats.mcnemar_test(Y_TRUE, Y_PRED_A, Y_PRED_B[:-1])
+    with pytest.raises(ValueError):
+        fast_stats.paired_permutation_test(Y_TRUE, Y_PRED_A, Y_PRED_B, pos_label=5)
+    with pytest.raises(ValueError):
+        fast_stats.paired_permutation_test(
+            Y_TRUE, Y_PRED_A, Y_PRED_B, n_permutations=0
+        )
+    with pytest.raises(ValueError):
+        fast_stats.paired_bootstrap_test(Y_TRUE, Y_PRED_A, Y_PRED_B, metric="auc")
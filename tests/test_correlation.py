@@ -0,0 +1,62 @@
+import numpy as np
+import pytest
+
+import fast_stats
+from fast_stats.exceptions import ShapeError
+
+X = np.array([1, 2, 3, 4, 5])
+Y = np.array([2, 4, 5, 4, 5])
+
+
+@pytest.mark.parametrize(
+    "metric,x,y,expected",
+    [
+        ("pearsonr", X, Y, 0.7745966692414834),
+        ("spearmanr", X, Y, 0.7378647873726218),
+        ("kendall_tau", X, Y, 6 / np.sqrt(80)),
+        (
+            "kendall_tau",
+            np.array([12, 2, 1, 12, 2]),
+            np.array([1, 4, 7, 1, 0]),
+            -0.4714045207910317,
+        ),
+        ("pearsonr", X, -X, -1.0),
+        ("spearmanr", X, X**3, 1.0),
+    ],
+)
+@pytest.mark.parametrize("dtype", [np.float32, np.float64])
+def test_correlation(metric, x, y, expected, dtype):
+    res = getattr(fast_stats, metric)(x.astype(dtype), y.astype(dtype))
+    assert np.isclose(res, expected)
+
+
+@pytest.mark.parametrize("metric", ["pearsonr", "spearmanr", "kendall_tau"])
+def test_correlation_columns(metric):
+    x = np.stack([X, X, X], axis=1)
+    y = np.stack([Y, -X, np.ones(5)], axis=1)
+    res = getattr(fast_stats, metric)(x, y)
+    expected = getattr(fast_stats, metric)(X, Y)
+    assert np.allclose(res, [expected, -1.0, np.nan], equal_nan=True)
+
+
+def test_correlation_nan():
+    y = np.array([2, 4, np.nan, 4, 5])
+    assert np.isnan(fast_stats.kendall_tau(X, y))
+
+
+def test_correlation_large_ties():
+    rng = np.random.default_rng(0)
+    x = rng.integers(0, 10, size=2000)
+    y = x + rng.integers(0, 5, size=2000)
+    # brute force tau-b over all pairs
+    dx = np.sign(x[:, None] - x[None, :])
+    dy = np.sign(y[:, None] - y[None, :])
+    expected = (dx * dy).sum() / np.sqrt((dx**2).sum() * (dy**2).sum())
+    assert np.isclose(fast_stats.kendall_tau(x, y), expected)
+
+
+def test_correlation_errors():
+    with pytest.raises(ShapeError):
+        fast_stats.pearsonr(X, Y[:4])
+    with pytest.raises(ValueError):
+        fast_stats.pearsonr(X[:1], Y[:1])
@@ -0,0 +1,88 @@
+import numpy as np
+import pytest
+
+import fast_stats
+from fast_stats.exceptions import ShapeError
+
+ARR = np.array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]])
+
+
+@pytest.mark.parametrize(
+    "arr,expected",
+    [
+        (
+            np.array([1, 2, 3, 4]),
+            {"count": 4, "mean": 2.5, "variance": 1.25, "min": 1, "max": 4},
+        ),
+        (
+            np.array([1, 2, 3, 4], dtype=np.float32),
+            {"skewness": 0.0, "kurtosis": -1.36},
+        ),
+        (
+            np.array([0.0, 0.0, 0.0, 1.0]),
+            {"std": np.sqrt(0.1875), "skewness": 2 / np.sqrt(3), "kurtosis": -2 / 3},
+        ),
+    ],
+)
+def test_describe(arr, expected):
+    res = fast_stats.describe(arr)
+    for k, v in expected.items():
+        assert np.isclose(res[k], v)
+
+
+@pytest.mark.parametrize(
+    "axis,ddof,mean,variance",
+    [
+        (0, 0, [2.5, 3.5, 4.5], [2.25, 2.25, 2.25]),
+        (1, 1, [2.0, 5.0], [1.0, 1.0]),
+        (-1, 0, [2.0, 5.0], [2 / 3, 2 / 3]),
+    ],
+)
+def test_describe_axis(axis, ddof, mean, variance):
+    res = fast_stats.describe(ARR, axis, ddof)
+    assert np.allclose(res["mean"], mean)
+    assert np.allclose(res["variance"], variance)
+
+
+def test_describe_empty():
+    res = fast_stats.describe(np.array([]))
+    assert res["count"] == 0
+    assert np.isnan(res["mean"]) and np.isnan(res["variance"])
+
+
+@pytest.mark.parametrize("axis", [None, 0])
+def test_descriptive_stats_streaming(axis):
+    rng = np.random.default_rng(0)
+    arr = rng.normal(1e6, 3.0, size=(1000, 4))
+    expected = fast_stats.describe(arr, axis)
+
+    stream = fast_stats.DescriptiveStats(axis)
+    for chunk in np.array_split(arr[:600], 7):
+        stream.update(chunk)
+    other = fast_stats.DescriptiveStats(axis).update(arr[600:])
+    res = stream.merge(other).result()
+    for k, v in expected.items():
+        assert np.allclose(res[k], v)
+
+
+def test_describe_nan():
+    arr = np.array([[np.nan, 2.0, 3.0], [4.0, 5.0, 6.0]])
+    res = fast_stats.describe(arr)
+    assert all(np.isnan(res[k]) for k in ["mean", "variance", "min", "max"])
+
+    # NaN is kept by later values and by merging
+    res = fast_stats.describe(arr, axis=1)
+    assert np.isnan(res["min"][0]) and np.isnan(res["max"][0])
+    assert res["min"][1] == 4.0 and res["max"][1] == 6.0
+    stream = fast_stats.DescriptiveStats().update(arr[:1])
+    res = stream.merge(fast_stats.DescriptiveStats().update(arr[1:])).result()
+    assert np.isnan(res["min"]) and np.isnan(res["max"])
+
+
+def test_descriptive_stats_errors():
+    with pytest.raises(ShapeError):
+        fast_stats.DescriptiveStats(0).update(ARR).update(np.ones((2, 2)))
+    with pytest.raises(ValueError):
+        fast_stats.DescriptiveStats(0).merge(fast_stats.DescriptiveStats(1))
+    with pytest.raises(ValueError):
+        fast_stats.describe(ARR, axis=2)
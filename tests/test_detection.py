@@ -0,0 +1,200 @@
+import numpy as np
+import pytest
+
+import fast_stats
+
+# one true positive, then a false positive, then another true positive
+AP_FP_BETWEEN = (51 + 50 * 2 / 3) / 101
+
+
+def _random_detections(seed, n_images=8, n_classes=3):
+    # jittered copies of most ground truth boxes plus a few false positives
+    rng = np.random.default_rng(seed)
+    preds, target = [], []
+    for _ in range(n_images):
+        n = rng.integers(1, 8)
+        boxes = np.concatenate(
+            [rng.uniform(0, 400, (n, 2)), rng.uniform(4, 200, (n, 2))], axis=1
+        )
+        labels = rng.integers(1, n_classes + 1, n)
+        crowd = rng.uniform(size=n) < 0.1
+        target.append({"boxes": boxes, "labels": labels, "iscrowd": crowd})
+
+        keep = rng.uniform(size=n) < 0.8
+        jitter = boxes[keep] + rng.normal(0, 8, (keep.sum(), 4))
+        extra = np.concatenate(
+            [rng.uniform(0, 400, (2, 2)), rng.uniform(4, 200, (2, 2))], axis=1
+        )
+        dt_boxes = np.concatenate([jitter, extra])
+        dt_boxes[:, 2:] = np.maximum(dt_boxes[:, 2:], 1.0)
+        dt_labels = np.concatenate([labels[keep], rng.integers(1, n_classes + 1, 2)])
+        scores = rng.uniform(size=len(dt_boxes))
+        preds.append({"boxes": dt_boxes, "scores": scores, "labels": dt_labels})
+    return preds, target
+
+
+@pytest.mark.parametrize(
+    "preds,target,box_format,expected",
+    [
+        (
+            [{"boxes": [[10, 10, 50, 50]], "scores": [0.9], "labels": [0]}],
+            [{"boxes": [[10, 10, 50, 50]], "labels": [0]}],
+            "xywh",
+            {
+                "map": 1.0,
+                "map_50": 1.0,
+                "map_75": 1.0,
+                "map_small": -1.0,
+                "map_medium": 1.0,
+                "map_large": -1.0,
+                "mar_1": 1.0,
+                "mar_10": 1.0,
+                "mar_100": 1.0,
+                "mar_small": -1.0,
+                "mar_medium": 1.0,
+                "mar_large": -1.0,
+            },
+        ),
+        (
+            [
+                {
+                    "boxes": [
+                        [0, 0, 100, 100],
+                        [400, 400, 500, 500],
+                        [200, 200, 300, 300],
+                    ],
+                    "scores": [0.9, 0.8, 0.7],
+                    "labels": [0, 0, 0],
+                }
+            ],
+            [{"boxes": [[0, 0, 100, 100], [200, 200, 300, 300]], "labels": [0, 0]}],
+            "xyxy",
+            {
+                "map": AP_FP_BETWEEN,
+                "map_50": AP_FP_BETWEEN,
+                "map_large": AP_FP_BETWEEN,
+                "map_small": -1.0,
+                "mar_1": 0.5,
+                "mar_10": 1.0,
+                "mar_large": 1.0,
+            },
+        ),
+        (
+            # the detection inside the crowd box is ignored instead of a false positive
+            [
+                {
+                    "boxes": [[10, 10, 50, 50], [200, 200, 250, 250]],
+                    "scores": [0.9, 0.8],
+                    "labels": [0, 0],
+                }
+            ],
+            [
+                {
+                    "boxes": [[0, 0, 100, 100], [200, 200, 250, 250]],
+                    "labels": [0, 0],
+                    "iscrowd": [1, 0],
+                }
+            ],
+            "xyxy",
+            {"map": 1.0, "map_medium": 1.0, "map_large": -1.0},
+        ),
+        (
+            # the class 1 detection has IoU 0.72 with its box
+            [
+                {"boxes": [[0, 0, 10, 10]], "scores": [0.9], "labels": [0]},
+                {"boxes": [[0, 0, 10, 7.2]], "scores": [0.9], "labels": [1]},
+            ],
+            [
+                {"boxes": [[0, 0, 10, 10]], "labels": [0]},
+                {"boxes": [[0, 0, 10, 10]], "labels": [1]},
+            ],
+            "xyxy",
+            {
+                "map": 0.75,
+                "map_50": 1.0,
+                "map_75": 0.5,
+                "map_small": 0.75,
+                "mar_100": 0.75,
+                "mar_small": 0.75,
+            },
+        ),
+    ],
+)
+def test_detection_map(preds, target, box_format, expected):
+    res = fast_stats.detection_map(preds, target, box_format)
+    for key, value in expected.items():
+        assert np.isclose(res[key], value)
+
+
+def test_detection_map_empty_image():
+    preds = [
+        {"boxes": [[0, 0, 10, 10]], "scores": [0.9], "labels": [0]},
+        {"boxes": [[0, 0, 10, 10]], "scores": [0.8], "labels": [0]},
+    ]
+    target = [
+        {"boxes": [[0, 0, 10, 10]], "labels": [0]},
+        {"boxes": np.zeros((0, 4)), "labels": np.zeros(0)},
+    ]
+    res = fast_stats.detection_map(preds, target, iou_thresholds=[0.5])
+    assert np.isclose(res["map"], 1.0)
+    assert res["map_75"] == -1.0
+
+
+def test_detection_map_length():
+    with pytest.raises(ValueError):
+        fast_stats.detection_map([], [{"boxes": [], "labels": []}])
+
+
+def test_detection_map_no_boxes():
+    res = fast_stats.detection_map([], [])
+    assert len(res) == 12
+    assert all(v == -1.0 for v in res.values())
+
+    empty = {"boxes": np.zeros((0, 4)), "scores": np.zeros(0), "labels": []}
+    res = fast_stats.detection_map([empty, empty], [empty, empty])
+    assert all(v == -1.0 for v in res.values())
+
+
+def test_detection_map_pycocotools():
+    coco = pytest.importorskip("pycocotools.coco")
+    cocoeval = pytest.importorskip("pycocotools.cocoeval")
+    preds, target = _random_detections(0)
+
+    # COCO ids start at 1, a matched ground truth id of 0 reads as unmatched
+    images, annotations, results = [], [], []
+    for image, (p, t) in enumerate(zip(preds, target), start=1):
+        images.append({"id": image})
+        for box, label, crowd in zip(t["boxes"], t["labels"], t["iscrowd"]):
+            annotations.append(
+                {
+                    "id": len(annotations) + 1,
+                    "image_id": image,
+                    "category_id": int(label),
+                    "bbox": box.tolist(),
+                    "area": float(box[2] * box[3]),
+                    "iscrowd": int(crowd),
+                }
+            )
+        for box, label, score in zip(p["boxes"], p["labels"], p["scores"]):
+            results.append(
+                {
+                    "image_id": image,
+                    "category_id": int(label),
+                    "bbox": box.tolist(),
+                    "score": float(score),
+                }
+            )
+    gt = coco.COCO()
+    gt.dataset = {
+        "images": images,
+        "annotations": annotations,
+        "categories": [{"id": c} for c in range(1, 4)],
+    }
+    gt.createIndex()
+    evaluator = cocoeval.COCOeval(gt, gt.loadRes(results), "bbox")
+    evaluator.evaluate()
+    evaluator.accumulate()
+    evaluator.summarize()
+
+    res = fast_stats.detection_map(preds, target, "xywh")
+    assert np.allclose(list(res.values()), evaluator.stats)
@@ -0,0 +1,77 @@
+import numpy as np
+import pytest
+
+import fast_stats
+
+
+@pytest.mark.parametrize(
+    "y_true,y_pred,kwargs,expected",
+    [
+        (
+            np.array([[1, 1, 0, 0], [1, 0, 0, 0]], dtype=np.uint8),
+            np.array([[1, 0, 1, 0], [0, 0, 0, 0]], dtype=np.uint8),
+            {},
+            0.4,
+        ),
+        (
+            np.array([[1, 1, 0, 0], [1, 0, 0, 0]], dtype=bool),
+            np.array([[1, 0, 1, 0], [0, 0, 0, 0]], dtype=bool),
+            {"per_sample": True},
+            np.array([0.5, 0.0]),
+        ),
+        (
+            np.array([[1, 1, 0, 0], [0, 0, 0, 0]], dtype=np.uint8),
+            np.array([[1, 0, 1, 0], [0, 0, 0, 0]], dtype=np.uint8),
+            {"per_sample": True, "zero_division": "zero"},
+            np.array([0.5, 0.0]),
+        ),
+        (
+            np.zeros(4, dtype=np.uint8),
+            np.zeros(4, dtype=np.uint8),
+            {"zero_division": "zero"},
+            0.0,
+        ),
+    ],
+)
+def test_dice_score(y_true, y_pred, kwargs, expected):
+    assert np.allclose(fast_stats.dice_score(y_true, y_pred, **kwargs), expected)
+
+
+def test_dice_score_zero_division():
+    zeros = np.zeros((2, 4), dtype=np.uint8)
+    assert fast_stats.dice_score(zeros, zeros) is None
+    assert np.isnan(fast_stats.dice_score(zeros, zeros, per_sample=True)).all()
+
+
+@pytest.mark.parametrize(
+    "y_true,y_pred,kwargs,expected",
+    [
+        (np.array([0, 0, 0, 1]), np.array([0, 0, 1, 1]), {}, 22 / 32),
+        (
+            np.array([0, 0, 0, 1]),
+            np.array([0, 0, 1, 1]),
+            {"weight_type": "simple"},
+            5 / 7,
+        ),
+        (
+            np.array([0, 0, 0, 1]),
+            np.array([0, 0, 1, 1]),
+            {"weight_type": "uniform"},
+            0.75,
+        ),
+        (
+            np.array([[0, 0, 0, 1], [1, 1, 0, 0]]),
+            np.array([[0, 0, 1, 1], [1, 1, 0, 0]]),
+            {"per_sample": True},
+            np.array([22 / 32, 1.0]),
+        ),
+        (
+            np.array([[0, 0], [0, 1]]),
+            np.array([[0, 1], [0, 1]]),
+            {"per_sample": True, "labels": [0, 1]},
+            np.array([0.5, 1.0]),
+        ),  # class missing from a sample gets the largest weight
+    ],
+)
+def test_generalized_dice(y_true, y_pred, kwargs, expected):
+    assert np.allclose(fast_stats.generalized_dice(y_true, y_pred, **kwargs), expected)
@@ -0,0 +1,94 @@
+import numpy as np
+import pytest
+
+import fast_stats
+
+Y_TRUE = np.zeros((6, 6), dtype=np.uint8)
+Y_TRUE[0:2, 0:2] = 1
+Y_TRUE[3:6, 3:6] = 1
+Y_TRUE[0, 5] = 1
+Y_PRED = np.zeros((6, 6), dtype=np.uint8)
+Y_PRED[0:2, 0:2] = 1
+Y_PRED[3:6, 4:6] = 1
+Y_PRED[5, 0] = 1
+DIAGONAL = np.eye(3, dtype=bool)
+
+
+@pytest.mark.parametrize(
+    "mask,connectivity,expected",
+    [
+        (Y_TRUE, None, 3),
+        (DIAGONAL, 4, 3),
+        (DIAGONAL, 8, 1),
+        (np.eye(3, dtype=bool)[np.newaxis], 6, 3),
+        (np.eye(3, dtype=bool)[np.newaxis], 26, 1),
+    ],
+)
+def test_label_components(mask, connectivity, expected):
+    labels, n = fast_stats.label_components(mask, connectivity)
+    assert n == expected
+    assert labels.max() == expected
+    assert np.array_equal(labels > 0, mask > 0)
+
+
+def test_label_components_connectivity():
+    with pytest.raises(ValueError):
+        fast_stats.label_components(DIAGONAL, 6)
+
+
+@pytest.mark.parametrize(
+    "y_true,y_pred,iou_threshold,zero_division,expected",
+    [
+        (
+            Y_TRUE,
+            Y_PRED,
+            0.5,
+            "none",
+            {
+                "tp": 2,
+                "fp": 1,
+                "fn": 1,
+                "precision": 2 / 3,
+                "recall": 2 / 3,
+                "f1-score": 2 / 3,
+            },
+        ),
+        (
+            Y_TRUE,
+            Y_PRED,
+            0.7,
+            "none",
+            {
+                "tp": 1,
+                "fp": 2,
+                "fn": 2,
+                "precision": 1 / 3,
+                "recall": 1 / 3,
+                "f1-score": 1 / 3,
+            },
+        ),
+        (
+            np.zeros((3, 3), dtype=bool),
+            np.zeros((3, 3), dtype=bool),
+            0.5,
+            "none",
+            {"tp": 0, "fp": 0, "fn": 0, "precision": None, "recall": None},
+        ),
+        (
+            np.zeros((3, 3), dtype=bool),
+            np.zeros((3, 3), dtype=bool),
+            0.5,
+            "zero",
+            {"tp": 0, "fp": 0, "fn": 0, "precision": 0.0, "f1-score": 0.0},
+        ),
+    ],
+)
+def test_instance_stats(y_true, y_pred, iou_threshold, zero_division, expected):
+    res = fast_stats.instance_stats(
+        y_true, y_pred, iou_threshold, zero_division=zero_division
+    )
+    for key, value in expected.items():
+        if value is None:
+            assert res[key] is None
+        else:
+            assert np.isclose(res[key], value)
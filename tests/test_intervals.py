@@ -0,0 +1,60 @@
+import numpy as np
+import pytest
+
+import fast_stats
+from fast_stats.exceptions import ShapeError
+
+
+@pytest.mark.parametrize(
+    "method,expected",
+    [
+        (
+            "wilson",
+            [[0.01787621, 0.40415003], [0.23659309, 0.76340691], [0.0, 0.2775328]],
+        ),
+        (
+            "clopper_pearson",
+            [[0.00252858, 0.44501612], [0.18708603, 0.81291397], [0.0, 0.30849711]],
+        ),
+        (
+            "agresti_coull",
+            [[0.0, 0.42596774], [0.23659309, 0.76340691], [0.0, 0.32088731]],
+        ),
+    ],
+)
+def test_proportion_ci(method, expected):
+    res = fast_stats.proportion_ci(np.array([1, 5, 0]), np.array([10, 10, 10]), method)
+    assert np.allclose(res, expected)
+    # the upper bound of all successes mirrors the lower bound of none
+    low, high = fast_stats.proportion_ci(10, 10, method)
+    assert np.isclose(low, 1 - expected[2][1]) and high == 1.0
+
+
+@pytest.mark.parametrize("method", ["wilson", "clopper_pearson", "agresti_coull"])
+def test_proportion_ci_confidence(method):
+    narrow = fast_stats.proportion_ci(30, 100, method, confidence=0.8)
+    wide = fast_stats.proportion_ci(30, 100, method, confidence=0.99)
+    assert wide[0] < narrow[0] < 0.3 < narrow[1] < wide[1]
+    assert np.isnan(fast_stats.proportion_ci(0, 0, method)).all()
+
+
+@pytest.mark.parametrize("method", ["wilson", "clopper_pearson", "agresti_coull"])
+@pytest.mark.parametrize("confidence", [0.8, 0.9, 0.95, 0.99])
+def test_proportion_ci_ends(method, confidence):
+    # no successes start exactly at 0 and all successes end exactly at 1
+    n = np.arange(1, 50)
+    res = fast_stats.proportion_ci(np.zeros_like(n), n, method, confidence)
+    assert np.all(res[:, 0] == 0.0)
+    res = fast_stats.proportion_ci(n, n, method, confidence)
+    assert np.all(res[:, 1] == 1.0)
+
+
+def test_proportion_ci_exceptions():
+    with pytest.raises(ShapeError):
+        fast_stats.proportion_ci(np.array([1, 2]), np.array([3]))
+    with pytest.raises(ValueError):
+        fast_stats.proportion_ci(4, 3)
+    with pytest.raises(ValueError):
+        fast_stats.proportion_ci(1, 3, confidence=1.0)
+    with pytest.raises(ValueError):
+        fast_stats.proportion_ci(1, 3, "wald")
@@ -47,3 +47,53 @@ import fast_stats
 )
 def test_iou(arr1, arr2, zero_division, expected):
     assert fast_stats.iou(arr1, arr2, zero_division) == expected
+
+
+Y_TRUE = np.array([[0, 0, 1], [1, 2, 255]], dtype=np.uint8)
+Y_PRED = np.array([[0, 1, 1], [1, 2, 0]], dtype=np.uint8)
+
+
+@pytest.mark.parametrize(
+    "y_true,y_pred,kwargs,expected",
+    [
+        (Y_TRUE, Y_PRED, {"ignore_index": 255}, np.array([0.5, 2 / 3, 1.0])),
+        (Y_TRUE, Y_PRED, {}, np.array([1 / 3, 2 / 3, 1.0, 0.0])),
+        (
+            Y_TRUE,
+            Y_PRED,
+            {"ignore_index": 255, "labels": [1, 2]},
+            np.array([2 / 3, 1.0]),
+        ),  # subset of labels
+        (
+            Y_TRUE,
+            Y_PRED,
+            {"ignore_index": 255, "average": "micro"},
+            2 / 3,
+        ),
+        (
+            Y_TRUE,
+            Y_PRED,
+            {"ignore_index": 255, "average": "macro"},
+            13 / 18,
+        ),
+        (
+            np.array([1, 1]),
+            np.array([1, 7]),
+            {"labels": [1]},
+            np.array([0.5]),
+        ),  # predictions outside labels are FN
+        (
+            Y_TRUE,
+            Y_PRED,
+            {"ignore_index": 255, "labels": [0, 3], "zero_division": "zero"},
+            np.array([0.5, 0.0]),
+        ),
+    ],
+)
+def test_jaccard_score(y_true, y_pred, kwargs, expected):
+    assert np.allclose(fast_stats.jaccard_score(y_true, y_pred, **kwargs), expected)
+
+
+def test_mean_iou():
+    assert np.isclose(fast_stats.mean_iou(Y_TRUE, Y_PRED, ignore_index=255), 13 / 18)
+    assert np.isnan(fast_stats.jaccard_score(Y_TRUE, Y_PRED, labels=[3])[0])
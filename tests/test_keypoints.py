@@ -0,0 +1,60 @@
+import numpy as np
+import pytest
+
+import fast_stats
+
+Y_TRUE = np.array(
+    [
+        [[0, 0, 2], [10, 0, 2], [0, 10, 0]],
+        [[0, 0, 0], [10, 0, 0], [0, 10, 0]],
+    ]
+)
+Y_PRED = np.array(
+    [
+        [[0, 0], [13, 4], [50, 50]],
+        [[0, 0], [10, 0], [0, 10]],
+    ]
+)
+AREAS = np.array([100, 100])
+SIGMAS = np.array([0.5, 0.5, 0.5])
+OKS_0 = (1 + np.exp(-25 / 200)) / 2
+
+
+@pytest.mark.parametrize(
+    "zero_division,per_sample,expected",
+    [
+        ("none", True, [OKS_0, np.nan]),
+        ("zero", True, [OKS_0, 0.0]),
+        ("none", False, OKS_0),
+        ("zero", False, OKS_0 / 2),
+    ],
+)
+def test_keypoint_oks(zero_division, per_sample, expected):
+    res = fast_stats.keypoint_oks(
+        Y_TRUE, Y_PRED, AREAS, SIGMAS, zero_division, per_sample
+    )
+    assert np.allclose(res, expected, equal_nan=True)
+
+
+def test_keypoint_oks_coco_sigmas():
+    y_true = np.ones((1, 17, 3))
+    assert np.isclose(fast_stats.keypoint_oks(y_true, y_true, np.array([1.0])), 1.0)
+    with pytest.raises(ValueError):
+        fast_stats.keypoint_oks(Y_TRUE, Y_PRED, AREAS)
+
+
+@pytest.mark.parametrize(
+    "alpha,normalize,per_sample,expected",
+    [
+        (0.2, None, False, 0.5),
+        (0.2, None, True, [0.5, np.nan]),
+        (0.5, None, False, 1.0),
+        (0.2, np.array([30.0, 1.0]), False, 1.0),
+    ],
+)
+@pytest.mark.filterwarnings("error")
+def test_keypoint_pck(alpha, normalize, per_sample, expected):
+    res = fast_stats.keypoint_pck(
+        Y_TRUE, Y_PRED, alpha, normalize, per_sample=per_sample
+    )
+    assert np.allclose(res, expected, equal_nan=True)
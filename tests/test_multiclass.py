@@ -422,6 +422,57 @@ def test_f1_score(y_true, y_pred, kwargs, expected):
     assert np.allclose(fast_stats.f1_score(y_true, y_pred, **kwargs), expected)
 
 
+@pytest.mark.parametrize(
+    "y_true,y_pred,beta,kwargs,expected",
+    [
+        (
+            np.array([1, 2, 3, 1, 2, 3], dtype=np.uint64),
+            np.array([1, 2, 3, 1, 2, 3], dtype=np.uint64),
+            2.0,
+            {},
+            np.array([1.0, 1.0, 1.0]),
+        ),  # perfect
+        (
+            np.array([1, 2, 3, 1, 2, 3], dtype=np.uint64),
+            np.array([1, 2, 3, 2, 3, 1], dtype=np.uint64),
+            0.5,
+            {"labels": [1, 2, 3]},
+            np.array([0.5, 0.5, 0.5]),
+        ),  # 50%
+        (
+            np.array([1, 1, 1, 2], dtype=np.uint64),
+            np.array([1, 2, 2, 2], dtype=np.uint64),
+            2.0,
+            {},
+            np.array([5 / 13, 5 / 7]),
+        ),  # recall weighted
+        (
+            np.array([1, 1, 1, 2], dtype=np.uint64),
+            np.array([1, 2, 2, 2], dtype=np.uint64),
+            2.0,
+            {"average": "micro", "zero_division": "none"},
+            0.5,
+        ),  # micro
+        (
+            np.array([1, 1, 1, 2], dtype=np.uint64),
+            np.array([1, 2, 2, 2], dtype=np.uint64),
+            2.0,
+            {"average": "macro", "zero_division": "zero"},
+            (5 / 13 + 5 / 7) / 2,
+        ),  # macro
+        (
+            np.array([1, 1, 2, 2], dtype=np.uint64),
+            np.array([1, 1, 1, 1], dtype=np.uint64),
+            1.0,
+            {"labels": [1, 2], "zero_division": "zero"},
+            np.array([2 / 3, 0.0]),
+        ),  # zero division
+    ],
+)
+def test_fbeta_score(y_true, y_pred, beta, kwargs, expected):
+    assert np.allclose(fast_stats.fbeta_score(y_true, y_pred, beta, **kwargs), expected)
+
+
 @pytest.mark.parametrize(
     "y_true,y_pred,kwargs,expected",
     [
@@ -585,3 +636,71 @@ def test_f1_score(y_true, y_pred, kwargs, expected):
 )
 def test_stats(y_true, y_pred, kwargs, expected):
     assert len(list(diff(fast_stats.stats(y_true, y_pred, **kwargs), expected))) == 0
+
+
+@pytest.mark.parametrize(
+    "func,kwargs,expected",
+    [
+        (fast_stats.precision, {}, np.array([1.0, 1.0, 0.5])),
+        (fast_stats.recall, {}, np.array([1.0, 0.5, 1.0])),
+        (fast_stats.f1_score, {"average": "micro"}, 0.75),
+        (fast_stats.fbeta_score, {"beta": 1.0}, np.array([1.0, 2 / 3, 2 / 3])),
+    ],
+)
+def test_class_axis(func, kwargs, expected):
+    y_true = np.array([[0, 1], [2, 1]])
+    scores = np.array(
+        [
+            [[0.9, 0.1], [0.05, 0.2], [0.05, 0.7]],
+            [[0.1, 0.3], [0.1, 0.6], [0.8, 0.1]],
+        ]
+    )
+    assert np.allclose(func(y_true, scores, class_axis=1, **kwargs), expected)
+
+
+def test_stats_class_axis():
+    y_true = np.array([0, 1, 1])
+    scores = np.array([[0.8, 0.2], [0.4, 0.6], [0.7, 0.3]])
+    result = fast_stats.stats(y_true, scores, class_axis=1)
+    assert np.array_equal(result["labels"], np.array([0, 1]))
+    assert np.allclose(result["precision"], np.array([0.5, 1.0]))
+    assert np.allclose(result["support"], np.array([1, 2]))
+
+
+@pytest.mark.parametrize("ci", ["wilson", "clopper_pearson", "agresti_coull"])
+def test_stats_ci(ci):
+    y_true = np.array([0, 0, 1, 1, 1, 2, 2, 2])
+    y_pred = np.array([0, 1, 1, 1, 2, 2, 2, 0])
+    res = fast_stats.stats(y_true, y_pred, ci=ci)
+    assert res["accuracy"] == 5 / 8
+    assert res["precision_ci"].shape == res["recall_ci"].shape == (3, 2)
+    # every class has as many predictions as true values
+    expected = fast_stats.proportion_ci(np.array([1, 2, 2]), np.array([2, 3, 3]), ci)
+    assert np.allclose(res["precision_ci"], expected)
+    assert np.allclose(res["recall_ci"], expected)
+    assert np.allclose(res["accuracy_ci"], fast_stats.proportion_ci(5, 8, ci))
+
+    res = fast_stats.stats(y_true, y_pred, average="micro", ci=ci)
+    assert np.allclose(res["precision_ci"], fast_stats.proportion_ci(5, 8, ci))
+    with pytest.raises(ValueError):
+        fast_stats.stats(y_true, y_pred, average="macro", ci=ci)
+
+
+@pytest.mark.parametrize("average", ["none", "micro", "macro"])
+def test_stats_groups(average):
+    y_true = np.array([[0, 1, 2, 2], [1, 1, 0, 2], [2, 0, 0, 1]])
+    y_pred = np.array([[0, 2, 2, 1], [1, 0, 0, 2], [2, 2, 0, 1]])
+    groups = np.array([1, 0, 1])
+    res = fast_stats.stats(y_true, y_pred, average=average, groups=groups)
+    assert list(res) == [0, 1]
+    for g, stats in res.items():
+        mask = groups == g
+        expected = fast_stats.stats(
+            y_true[mask], y_pred[mask], labels=[0, 1, 2], average=average
+        )
+        assert stats.keys() == expected.keys()
+        for k in stats:
+            assert np.allclose(stats[k], expected[k], equal_nan=True)
+
+    with pytest.raises(ValueError):
+        fast_stats.stats(y_true, y_pred, class_axis=1, groups=groups)
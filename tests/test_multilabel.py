@@ -0,0 +1,96 @@
+import numpy as np
+import pytest
+
+import fast_stats
+from fast_stats.exceptions import ShapeError
+
+Y_TRUE = np.array([[1, 0, 1], [0, 1, 0], [1, 1, 0]], dtype=np.uint8)
+Y_PRED = np.array([[1, 0, 0], [0, 1, 1], [1, 0, 0]], dtype=np.uint8)
+
+
+@pytest.mark.parametrize(
+    "y_true,y_pred,expected",
+    [
+        (
+            Y_TRUE,
+            Y_PRED,
+            np.array([[[1, 0], [0, 2]], [[1, 0], [1, 1]], [[1, 1], [1, 0]]]),
+        ),
+        (
+            Y_TRUE.astype(bool),
+            Y_PRED.astype(bool),
+            np.array([[[1, 0], [0, 2]], [[1, 0], [1, 1]], [[1, 1], [1, 0]]]),
+        ),  # bool
+        (
+            np.ones((2, 2, 2), dtype=np.int64),
+            np.ones((2, 2, 2), dtype=np.int64),
+            np.array([[[0, 0], [0, 4]], [[0, 0], [0, 4]]]),
+        ),  # leading axes are samples
+    ],
+)
+def test_multilabel_confusion_matrix(y_true, y_pred, expected):
+    assert np.array_equal(
+        fast_stats.multilabel_confusion_matrix(y_true, y_pred), expected
+    )
+
+
+@pytest.mark.parametrize(
+    "func,kwargs,expected",
+    [
+        (fast_stats.multilabel_precision, {}, np.array([1.0, 1.0, 0.0])),
+        (fast_stats.multilabel_precision, {"average": "micro"}, 0.75),
+        (fast_stats.multilabel_precision, {"average": "macro"}, 2 / 3),
+        (fast_stats.multilabel_precision, {"average": "weighted"}, 0.8),
+        (fast_stats.multilabel_precision, {"average": "samples"}, 2.5 / 3),
+        (fast_stats.multilabel_recall, {}, np.array([1.0, 0.5, 0.0])),
+        (fast_stats.multilabel_recall, {"average": "micro"}, 0.6),
+        (fast_stats.multilabel_recall, {"average": "macro"}, 0.5),
+        (fast_stats.multilabel_recall, {"average": "weighted"}, 0.6),
+        (fast_stats.multilabel_recall, {"average": "samples"}, 2 / 3),
+        (
+            fast_stats.multilabel_f1_score,
+            {"zero_division": "zero"},
+            np.array([1.0, 2 / 3, 0.0]),
+        ),
+        (fast_stats.multilabel_f1_score, {"average": "micro"}, 2 / 3),
+        (
+            fast_stats.multilabel_f1_score,
+            {"average": "macro", "zero_division": "zero"},
+            (1 + 2 / 3) / 3,
+        ),
+        (
+            fast_stats.multilabel_f1_score,
+            {"average": "weighted", "zero_division": "zero"},
+            (2 + 4 / 3) / 5,
+        ),
+        (fast_stats.multilabel_f1_score, {"average": "samples"}, 2 / 3),
+    ],
+)
+def test_multilabel_stats(func, kwargs, expected):
+    assert np.allclose(func(Y_TRUE, Y_PRED, **kwargs), expected)
+
+
+def test_multilabel_zero_division():
+    result = fast_stats.multilabel_f1_score(Y_TRUE, Y_PRED, zero_division="none")
+    assert np.isnan(result[2])
+
+
+def test_hamming_loss():
+    assert np.isclose(fast_stats.hamming_loss(Y_TRUE, Y_PRED), 1 / 3)
+
+
+@pytest.mark.parametrize(
+    "y_true,y_pred,expected",
+    [
+        (Y_TRUE, Y_PRED, 0.0),
+        (Y_TRUE, Y_TRUE, 1.0),
+        (Y_TRUE, np.array([[1, 0, 1], [0, 1, 0], [0, 0, 0]], dtype=np.uint8), 2 / 3),
+    ],
+)
+def test_subset_accuracy(y_true, y_pred, expected):
+    assert np.isclose(fast_stats.subset_accuracy(y_true, y_pred), expected)
+
+
+def test_multilabel_shape():
+    with pytest.raises(ShapeError):
+        fast_stats.multilabel_precision(np.ones(4), np.ones(4))
@@ -0,0 +1,49 @@
+import numpy as np
+import pytest
+
+import fast_stats
+
+BOXES = np.array([[0, 0, 10, 10], [1, 0, 11, 10], [20, 20, 30, 30]])
+SCORES = np.array([0.9, 0.8, 0.7])
+IOU_01 = 9 / 11
+
+
+@pytest.mark.parametrize(
+    "boxes,scores,iou_threshold,box_format,expected",
+    [
+        (BOXES, SCORES, 0.5, "xyxy", [0, 2]),
+        (BOXES, SCORES, 0.9, "xyxy", [0, 1, 2]),
+        (BOXES, SCORES[::-1].copy(), 0.5, "xyxy", [2, 1]),
+        (np.array([[0, 0, 10, 10], [1, 0, 10, 10]]), SCORES[:2], 0.5, "xywh", [0]),
+        (np.zeros((0, 4)), np.zeros(0), 0.5, "xyxy", []),
+    ],
+)
+def test_nms(boxes, scores, iou_threshold, box_format, expected):
+    keep = fast_stats.nms(boxes, scores, iou_threshold, box_format)
+    assert keep.tolist() == expected
+
+
+@pytest.mark.parametrize(
+    "labels,expected",
+    [
+        (np.array([0, 1, 0]), [0, 1, 2]),
+        (np.array([1, 1, 1]), [0, 2]),
+    ],
+)
+def test_batched_nms(labels, expected):
+    keep = fast_stats.batched_nms(BOXES, SCORES, labels, 0.5)
+    assert keep.tolist() == expected
+
+
+@pytest.mark.parametrize(
+    "method,score_threshold,expected_keep,expected_scores",
+    [
+        ("linear", 0.001, [0, 2, 1], [0.9, 0.7, 0.8 * (1 - IOU_01)]),
+        ("linear", 0.3, [0, 2], [0.9, 0.7]),
+        ("gaussian", 0.001, [0, 2, 1], [0.9, 0.7, 0.8 * np.exp(-(IOU_01**2) / 0.5)]),
+    ],
+)
+def test_soft_nms(method, score_threshold, expected_keep, expected_scores):
+    keep, scores = fast_stats.soft_nms(BOXES, SCORES, 0.3, 0.5, score_threshold, method)
+    assert keep.tolist() == expected_keep
+    assert np.allclose(scores, expected_scores)
@@ -0,0 +1,92 @@
+import numpy as np
+import pytest
+
+import fast_stats
+
+TRUE_CLASSES = np.array([[1, 1, 2, 2]] * 4)
+TRUE_INSTANCES = np.array([[1, 1, 0, 0]] * 2 + [[2, 2, 0, 0]] * 2)
+PRED_CLASSES = TRUE_CLASSES.copy()
+PRED_CLASSES[3, 1] = 2
+PRED_INSTANCES = TRUE_INSTANCES.copy()
+PRED_INSTANCES[3, 1] = 0
+SPLIT_INSTANCES = TRUE_INSTANCES.copy()
+SPLIT_INSTANCES[3, :2] = 3
+
+VOID_TRUE_CLASSES = np.array([1, 1, 1, 0, 0, 0])
+VOID_PRED_CLASSES = np.array([1, 1, 1, 1, 1, 2])
+VOID_INSTANCES = np.zeros(6, dtype=np.int32)
+
+
+@pytest.mark.parametrize(
+    "y_pred_instances,y_pred_classes,average,expected",
+    [
+        (
+            PRED_INSTANCES,
+            PRED_CLASSES,
+            "none",
+            {
+                "pq": np.array([0.875, 8 / 9]),
+                "sq": np.array([0.875, 8 / 9]),
+                "rq": np.array([1.0, 1.0]),
+                "support": np.array([2, 1]),
+            },
+        ),
+        (
+            PRED_INSTANCES,
+            PRED_CLASSES,
+            "macro",
+            {"pq": (0.875 + 8 / 9) / 2, "sq": (0.875 + 8 / 9) / 2, "rq": 1.0},
+        ),
+        (
+            PRED_INSTANCES,
+            PRED_CLASSES,
+            "micro",
+            {"pq": (1.75 + 8 / 9) / 3, "sq": (1.75 + 8 / 9) / 3, "rq": 1.0},
+        ),
+        (
+            SPLIT_INSTANCES,
+            TRUE_CLASSES,
+            "none",
+            {
+                "pq": np.array([0.4, 1.0]),
+                "sq": np.array([1.0, 1.0]),
+                "rq": np.array([0.4, 1.0]),
+            },
+        ),
+    ],
+)
+def test_panoptic_quality(y_pred_instances, y_pred_classes, average, expected):
+    res = fast_stats.panoptic_quality(
+        TRUE_INSTANCES,
+        TRUE_CLASSES,
+        y_pred_instances,
+        y_pred_classes,
+        average=average,
+    )
+    for key, value in expected.items():
+        assert np.allclose(res[key], value)
+
+
+@pytest.mark.parametrize(
+    "void_label,zero_division,expected",
+    [
+        (0, "none", {"pq": np.array([1.0, np.nan]), "labels": np.array([1, 2])}),
+        (0, "zero", {"pq": np.array([1.0, 0.0]), "labels": np.array([1, 2])}),
+        (
+            None,
+            "none",
+            {"pq": np.array([0.0, 0.6, 0.0]), "labels": np.array([0, 1, 2])},
+        ),
+    ],
+)
+def test_panoptic_quality_void(void_label, zero_division, expected):
+    res = fast_stats.panoptic_quality(
+        VOID_INSTANCES,
+        VOID_TRUE_CLASSES,
+        VOID_INSTANCES,
+        VOID_PRED_CLASSES,
+        void_label=void_label,
+        zero_division=zero_division,
+    )
+    for key, value in expected.items():
+        assert np.allclose(res[key], value, equal_nan=True)
@@ -0,0 +1,93 @@
+import numpy as np
+import pytest
+
+import fast_stats
+from fast_stats.exceptions import ShapeError
+
+Y_TRUE = np.array([0, 0, 0, 0, 1, 1, 1, 1, 1])
+Y_PROB = np.array([0.1, 0.2, 0.3, 0.4, 0.65, 0.7, 0.8, 0.9, 1.0])
+
+
+@pytest.mark.parametrize(
+    "y_prob,class_axis",
+    [
+        (np.array([0.9, 0.1, 0.2, 0.65]), None),
+        (np.array([[0.1, 0.9], [0.9, 0.1], [0.8, 0.2], [0.35, 0.65]]), 1),
+        (np.array([[0.1, 0.9], [0.9, 0.1], [0.8, 0.2], [0.35, 0.65]]).T, 0),
+    ],
+)
+@pytest.mark.parametrize("dtype", [np.float32, np.float64])
+def test_log_loss(y_prob, class_axis, dtype):
+    y_true = np.array([1, 0, 0, 1])
+    res = fast_stats.log_loss(y_true, y_prob.astype(dtype), class_axis=class_axis)
+    assert np.isclose(res, 0.21616187468057912)
+
+
+def test_log_loss_clipping_and_labels():
+    y_true = np.array([[True, False], [False, True]])
+    y_prob = np.array([[1.0, 1.0], [0.0, 0.5]])
+    res = fast_stats.log_loss(y_true, y_prob, eps=1e-6)
+    assert np.isclose(res, (-np.log(1e-6) + np.log(2)) / 4)
+    res = fast_stats.log_loss(np.array([3, 7]), np.array([0.25, 0.5]), labels=[7, 3])
+    assert np.isclose(res, -np.log(0.25 * 0.5) / 2)
+
+
+@pytest.mark.parametrize(
+    "y_prob,class_axis,expected",
+    [
+        (np.array([0.1, 0.9, 0.8, 0.3]), None, 0.0375),
+        (np.array([[0.9, 0.1], [0.1, 0.9], [0.2, 0.8], [0.7, 0.3]]), -1, 0.075),
+    ],
+)
+def test_brier_score_loss(y_prob, class_axis, expected):
+    y_true = np.array([0, 1, 1, 0])
+    res = fast_stats.brier_score_loss(y_true, y_prob, class_axis=class_axis)
+    assert np.isclose(res, expected)
+
+
+@pytest.mark.parametrize(
+    "strategy,prob_true,prob_pred",
+    [
+        ("uniform", [0.0, 0.5, 1.0], [0.2, 0.525, 0.85]),
+        ("quantile", [0.0, 2 / 3, 1.0], [0.2, 1.75 / 3, 0.9]),
+    ],
+)
+def test_calibration_curve(strategy, prob_true, prob_pred):
+    res = fast_stats.calibration_curve(Y_TRUE, Y_PROB, 3, strategy)
+    assert np.allclose(res[0], prob_true)
+    assert np.allclose(res[1], prob_pred)
+
+
+def test_calibration_errors():
+    ece = fast_stats.expected_calibration_error(Y_TRUE, Y_PROB, 3)
+    mce = fast_stats.maximum_calibration_error(Y_TRUE, Y_PROB, 3)
+    assert np.isclose(ece, (3 * 0.2 + 2 * 0.025 + 4 * 0.15) / 9)
+    assert np.isclose(mce, 0.2)
+
+
+def test_calibration_multiclass():
+    y_true = np.array([0, 1, 1])
+    y_prob = np.array([[0.7, 0.2, 0.1], [0.6, 0.3, 0.1], [0.2, 0.5, 0.3]])
+    ece = fast_stats.expected_calibration_error(y_true, y_prob, 2, class_axis=1)
+    # the 0.5 confidence sits on the edge and goes to the lower bin
+    assert np.isclose(ece, (0.5 + 2 * 0.15) / 3)
+
+
+def test_calibration_curve_edge():
+    # like sklearn a probability on an inner edge goes to the lower bin
+    y_true = np.array([0, 1, 0, 1])
+    y_prob = np.array([0.5, 0.5, 0.2, 0.9])
+    prob_true, prob_pred = fast_stats.calibration_curve(y_true, y_prob, 2)
+    assert np.allclose(prob_true, [1 / 3, 1.0])
+    assert np.allclose(prob_pred, [0.4, 0.9])
+    ece = fast_stats.expected_calibration_error(y_true, y_prob, 2)
+    assert np.isclose(ece, (3 * (0.4 - 1 / 3) + 0.1) / 4)
+
+
+def test_probabilistic_errors():
+    with pytest.raises(ShapeError):
+        fast_stats.log_loss(Y_TRUE, Y_PROB[:3])
+    with pytest.raises(ValueError):
+        fast_stats.log_loss(np.array([0, 2]), np.array([0.5, 0.5]))
+    with pytest.raises(ShapeError):
+        fast_stats.brier_score_loss(Y_TRUE, np.ones((9, 2)), class_axis=0)
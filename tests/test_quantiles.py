@@ -0,0 +1,98 @@
+import numpy as np
+import pytest
+
+import fast_stats
+
+ARR = np.array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]])
+
+
+@pytest.mark.parametrize(
+    "q,method,expected",
+    [
+        (0.5, "linear", 2.5),
+        ([0.25, 0.75], "linear", [1.75, 3.25]),
+        (0.25, "lower", 1.0),
+        (0.25, "higher", 2.0),
+        (0.25, "midpoint", 1.5),
+        (1.0, "linear", 4.0),
+    ],
+)
+def test_quantile(q, method, expected):
+    res = fast_stats.quantile(np.array([4, 1, 3, 2]), q, method=method)
+    assert np.allclose(res, expected)
+
+
+@pytest.mark.parametrize("axis", [None, 0, 1, -1])
+@pytest.mark.parametrize("dtype", [np.float32, np.float64])
+def test_quantile_matches_numpy(axis, dtype):
+    arr = np.random.default_rng(0).normal(size=(50, 7)).astype(dtype)
+    q = [0.0, 0.1, 0.5, 0.99, 1.0]
+    res = fast_stats.quantile(arr, q, axis)
+    assert np.allclose(res, np.quantile(arr.astype(np.float64), q, axis))
+
+
+def test_quantile_nan_and_errors():
+    assert np.isnan(fast_stats.quantile(np.array([1.0, np.nan]), 0.5))
+    with pytest.raises(ValueError):
+        fast_stats.quantile(ARR, 1.5)
+    with pytest.raises(ValueError):
+        fast_stats.quantile(ARR, 0.5, method="nearest")
+
+
+def test_tdigest_small_is_exact():
+    digest = fast_stats.TDigest().update(np.array([3, 1, 4, 1.5, 9]))
+    assert digest.quantile(0.5) == 3
+    assert np.allclose(digest.quantile([0, 0.25, 1]), [1, 1.5, 9])
+
+
+def test_tdigest_streaming():
+    arr = np.random.default_rng(0).normal(size=100_000)
+    q = [0.01, 0.5, 0.95, 0.99]
+    digest = fast_stats.TDigest()
+    for chunk in np.array_split(arr[:60_000], 6):
+        digest.update(chunk)
+    other = fast_stats.TDigest().update(arr[60_000:])
+    digest.merge(other)
+
+    assert digest.count == arr.size
+    assert (digest.min, digest.max) == (arr.min(), arr.max())
+    assert np.allclose(digest.quantile(q), np.quantile(arr, q), atol=0.02)
+    assert len(digest.centroids) <= digest.compression
+
+
+@pytest.mark.parametrize("bins", [2, [0.0, 0.5, 2.0], "auto", "fd", "sturges", "sqrt"])
+def test_histogram(bins):
+    arr = np.array([0.0, 0.5, 1.0, 1.5, 2.0, 0.2, 1.9, 0.7])
+    counts, edges = fast_stats.histogram(arr, bins)
+    expected_counts, expected_edges = np.histogram(arr, bins)
+    assert np.allclose(edges, expected_edges)
+    assert np.array_equal(counts, expected_counts)
+
+
+def test_histogram_range():
+    arr = np.array([-1.0, 0.0, 0.5, 1.0, 3.0, np.nan])
+    counts, edges = fast_stats.histogram(arr, 4, (0, 1))
+    assert np.array_equal(counts, [1, 0, 1, 1])
+    assert np.allclose(edges, [0, 0.25, 0.5, 0.75, 1])
+
+
+@pytest.mark.parametrize("bins", ["auto", "fd", "sturges", "sqrt"])
+def test_histogram_bin_edges_range(bins):
+    arr = np.random.default_rng(0).normal(size=500)
+    arr[:50] *= 20  # outliers beyond the range
+    edges = fast_stats.histogram_bin_edges(arr, bins, (-2, 2))
+    assert np.allclose(edges, np.histogram_bin_edges(arr, bins, (-2, 2)))
+
+
+def test_histogram_streaming():
+    arr = np.random.default_rng(0).normal(size=10_000)
+    hist = fast_stats.Histogram(20, (-3, 3))
+    for chunk in np.array_split(arr[:5000], 4):
+        hist.update(chunk)
+    hist.merge(fast_stats.Histogram(20, (-3, 3)).update(arr[5000:]))
+    assert np.array_equal(hist.counts, np.histogram(arr, 20, (-3, 3))[0])
+
+    with pytest.raises(ValueError):
+        fast_stats.Histogram(20)
+    with pytest.raises(ValueError):
+        hist.merge(fast_stats.Histogram(10, (-3, 3)))
@@ -0,0 +1,99 @@
+import numpy as np
+import pytest
+
+import fast_stats
+from fast_stats.exceptions import ShapeError
+
+RELEVANCE = np.array([[10, 0, 0, 1, 5], [0, 1, 0, 1, 0], [0, 0, 0, 0, 0]])
+SCORES = np.array(
+    [
+        [0.1, 0.2, 0.3, 4, 70],
+        [0.5, 0.4, 0.3, 0.2, 0.1],
+        [0.5, 0.4, 0.3, 0.2, 0.1],
+    ]
+)
+
+
+@pytest.mark.parametrize(
+    "metric,k,expected",
+    [
+        ("ndcg_score", None, [0.6956940443813076, 0.6509209298071326, np.nan]),
+        ("ndcg_score", 2, [0.4280562600295606, 0.38685280723454163, np.nan]),
+        ("mean_reciprocal_rank", None, [1.0, 0.5, np.nan]),
+        ("mean_reciprocal_rank", 1, [1.0, 0.0, np.nan]),
+        ("mean_average_precision", None, [0.8666666666666667, 0.5, np.nan]),
+        ("mean_average_precision", 2, [1.0, 0.25, np.nan]),
+        ("hit_rate", 1, [1.0, 0.0, np.nan]),
+        ("precision_at_k", 2, [1.0, 0.5, 0.0]),
+        ("recall_at_k", 2, [2 / 3, 0.5, np.nan]),
+    ],
+)
+def test_ranking_per_query(metric, k, expected):
+    res = getattr(fast_stats, metric)(RELEVANCE, SCORES, k, per_query=True)
+    assert np.allclose(res, expected, equal_nan=True)
+
+
+@pytest.mark.parametrize(
+    "zero_division,expected",
+    [
+        ("none", (1.0 + 0.5) / 2),
+        ("zero", (1.0 + 0.5) / 3),
+    ],
+)
+def test_ranking_zero_division(zero_division, expected):
+    res = fast_stats.mean_reciprocal_rank(
+        RELEVANCE, SCORES, zero_division=zero_division
+    )
+    assert np.isclose(res, expected)
+
+
+def test_ndcg_exponential_gain():
+    relevance = np.array([[3, 2, 3, 0, 1, 2]])
+    scores = np.array([[6, 5, 4, 3, 2, 1]])
+    dcg = 7 + 3 / np.log2(3) + 7 / 2 + 1 / np.log2(6) + 3 / np.log2(7)
+    idcg = 7 + 7 / np.log2(3) + 3 / 2 + 3 / np.log2(5) + 1 / np.log2(6)
+    res = fast_stats.ndcg_score(relevance, scores, exponential_gain=True)
+    assert np.isclose(res, dcg / idcg)
+
+
+def test_ranking_grouped():
+    # the same queries as ragged rows with shuffled items and arbitrary ids
+    query_ids = np.repeat([7, 3, 5], 5)
+    perm = np.random.default_rng(0).permutation(15)
+    res = fast_stats.ranking_stats(
+        RELEVANCE.ravel()[perm],
+        SCORES.ravel()[perm],
+        2,
+        query_ids[perm],
+        per_query=True,
+    )
+    expected = fast_stats.ranking_stats(RELEVANCE, SCORES, 2, per_query=True)
+    for key, value in expected.items():
+        assert np.allclose(res[key], value[[1, 2, 0]], equal_nan=True)
+
+
+def test_ranking_ragged():
+    relevance = np.array([1, 0, 0, 1, 1])
+    scores = np.array([0.1, 0.9, 0.2, 0.8, 0.3])
+    query_ids = np.array([0, 0, 0, 1, 1])
+    res = fast_stats.precision_at_k(relevance, scores, 3, query_ids, per_query=True)
+    assert np.allclose(res, [1 / 3, 1.0])
+
+
+def test_ranking_nan_scores():
+    scores = SCORES.copy()
+    scores[1, 3] = np.nan
+    # the relevant item with a NaN score ranks first instead of fourth
+    res = fast_stats.hit_rate(RELEVANCE, scores, 1, per_query=True)
+    assert np.allclose(res, [1.0, 1.0, np.nan], equal_nan=True)
+    res = fast_stats.mean_reciprocal_rank(RELEVANCE, scores, per_query=True)
+    assert np.allclose(res, [1.0, 1.0, np.nan], equal_nan=True)
+
+
+def test_ranking_errors():
+    with pytest.raises(ShapeError):
+        fast_stats.ndcg_score(RELEVANCE, SCORES[:2])
+    with pytest.raises(ShapeError):
+        fast_stats.ndcg_score(RELEVANCE[0], SCORES[0])
+    with pytest.raises(ValueError):
+        fast_stats.ndcg_score(RELEVANCE, SCORES, k=0)
@@ -0,0 +1,89 @@
+import numpy as np
+import pytest
+
+import fast_stats
+from fast_stats.exceptions import ShapeError
+
+Y_TRUE = np.array([3, -0.5, 2, 7])
+Y_PRED = np.array([2.5, 0.0, 2, 8])
+Y_TRUE_2D = np.array([[0.5, 1], [-1, 1], [7, -6]])
+Y_PRED_2D = np.array([[0, 2], [-1, 2], [8, -5]])
+
+
+@pytest.mark.parametrize(
+    "metric,expected",
+    [
+        ("mean_absolute_error", 0.5),
+        ("mean_squared_error", 0.375),
+        ("root_mean_squared_error", np.sqrt(0.375)),
+        ("median_absolute_error", 0.5),
+        ("mean_absolute_percentage_error", (0.5 / 3 + 1 + 1 / 7) / 4),
+        ("r2_score", 0.9486081370449679),
+        ("explained_variance_score", 0.9571734475374732),
+    ],
+)
+@pytest.mark.parametrize("dtype", [np.float32, np.float64])
+def test_regression_metrics(metric, expected, dtype):
+    res = getattr(fast_stats, metric)(Y_TRUE.astype(dtype), Y_PRED.astype(dtype))
+    assert np.isclose(res, expected)
+
+
+@pytest.mark.parametrize(
+    "metric,multioutput,expected",
+    [
+        ("mean_absolute_error", "raw_values", [0.5, 1.0]),
+        ("mean_squared_error", "uniform_average", 0.7083333333333334),
+        ("median_absolute_error", "raw_values", [0.5, 1.0]),
+        ("r2_score", "raw_values", [0.9654377880184332, 0.9081632653061225]),
+        ("r2_score", "variance_weighted", 0.9382566585956417),
+        ("explained_variance_score", "raw_values", [0.967741935483871, 1.0]),
+    ],
+)
+def test_regression_multioutput(metric, multioutput, expected):
+    res = getattr(fast_stats, metric)(Y_TRUE_2D, Y_PRED_2D, multioutput=multioutput)
+    assert np.allclose(res, expected)
+
+
+def test_regression_sample_weight():
+    y_true = np.array([1, 2, 3, 4])
+    y_pred = np.array([1, 3, 5, 10])
+    weights = np.array([1, 1, 2, 0])
+    assert np.isclose(fast_stats.mean_absolute_error(y_true, y_pred, weights), 1.25)
+    assert np.isclose(fast_stats.mean_squared_error(y_true, y_pred, weights), 2.25)
+    assert np.isclose(fast_stats.median_absolute_error(y_true, y_pred, weights), 1)
+
+
+def test_regression_nan():
+    y_pred = np.array([2.5, np.nan, 2, 8])
+    assert np.isnan(fast_stats.median_absolute_error(Y_TRUE, y_pred))
+    assert np.isnan(fast_stats.median_absolute_error(Y_TRUE, y_pred, np.ones(4)))
+
+
+@pytest.mark.parametrize(
+    "y_pred,expected",
+    [
+        (np.array([2.0, 2.0, 2.0]), 1.0),
+        (np.array([1.0, 2.0, 3.0]), 0.0),
+    ],
+)
+def test_regression_constant_y_true(y_pred, expected):
+    y_true = np.array([2.0, 2.0, 2.0])
+    assert fast_stats.r2_score(y_true, y_pred) == expected
+
+
+def test_regression_stats():
+    res = fast_stats.regression_stats(
+        Y_TRUE_2D, Y_PRED_2D, multioutput="variance_weighted"
+    )
+    assert np.isclose(res["r2"], 0.9382566585956417)
+    assert np.isclose(res["mae"], 0.75)
+    assert np.isclose(res["explained_variance"], (0.967741935483871 * 217 + 196) / 413)
+
+
+def test_regression_errors():
+    with pytest.raises(ShapeError):
+        fast_stats.mean_absolute_error(Y_TRUE, Y_PRED[:3])
+    with pytest.raises(ShapeError):
+        fast_stats.mean_absolute_error(Y_TRUE, Y_PRED, np.ones(3))
+    with pytest.raises(ValueError):
+        fast_stats.mean_absolute_error(Y_TRUE, Y_PRED, multioutput="variance_weighted")
@@ -0,0 +1,59 @@
+import numpy as np
+import pytest
+
+import fast_stats
+
+
+def _square(rows, cols, shape=(6, 6)):
+    mask = np.zeros(shape, dtype=np.uint8)
+    mask[rows[0] : rows[1], cols[0] : cols[1]] = 1
+    return mask
+
+
+Y_TRUE = _square((1, 5), (1, 5))
+Y_PRED = _square((1, 5), (2, 6))
+VOXEL_TRUE = np.array([[[1, 0, 0]]], dtype=bool)
+VOXEL_PRED = np.array([[[0, 0, 1]]], dtype=bool)
+
+
+@pytest.mark.parametrize(
+    "y_true,y_pred,percentile,spacing,expected",
+    [
+        (Y_TRUE, Y_PRED, 100, None, 1.0),
+        (Y_TRUE, Y_PRED, 50, None, 0.5),
+        (Y_TRUE, Y_PRED, 100, (1, 2), 2.0),
+        (Y_TRUE, Y_TRUE, 100, None, 0.0),
+        (VOXEL_TRUE, VOXEL_PRED, 95, None, 2.0),
+        (VOXEL_TRUE, VOXEL_PRED, 100, (1, 1, 0.5), 1.0),
+        (Y_TRUE, np.zeros_like(Y_TRUE), 100, None, None),
+    ],
+)
+def test_hausdorff_distance(y_true, y_pred, percentile, spacing, expected):
+    res = fast_stats.hausdorff_distance(y_true, y_pred, percentile, spacing)
+    if expected is None:
+        assert res is None
+    else:
+        assert np.isclose(res, expected)
+
+
+@pytest.mark.parametrize(
+    "y_true,y_pred,spacing,symmetric,expected",
+    [
+        (Y_TRUE, Y_PRED, None, True, 0.5),
+        (Y_TRUE, Y_PRED, None, False, 0.5),
+        (Y_TRUE, Y_PRED, (1, 2), True, 10 / 12),
+        (VOXEL_TRUE, VOXEL_PRED, (1, 1, 0.5), True, 1.0),
+        (np.zeros_like(Y_TRUE), np.zeros_like(Y_TRUE), None, True, None),
+    ],
+)
+def test_mean_surface_distance(y_true, y_pred, spacing, symmetric, expected):
+    res = fast_stats.mean_surface_distance(y_true, y_pred, spacing, symmetric)
+    if expected is None:
+        assert res is None
+    else:
+        assert np.isclose(res, expected)
+
+
+def test_spacing_length():
+    with pytest.raises(ValueError):
+        fast_stats.hausdorff_distance(Y_TRUE, Y_PRED, spacing=(1, 1, 1))
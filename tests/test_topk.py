@@ -0,0 +1,68 @@
+import numpy as np
+import pytest
+
+import fast_stats
+from fast_stats.exceptions import ShapeError
+
+SCORES = np.array(
+    [
+        [0.5, 0.2, 0.2, 0.1],
+        [0.3, 0.4, 0.2, 0.1],
+        [0.2, 0.4, 0.3, 0.1],
+        [0.7, 0.2, 0.05, 0.05],
+    ],
+    dtype=np.float32,
+)
+
+
+@pytest.mark.parametrize(
+    "y_true,scores,k,kwargs,expected",
+    [
+        (np.array([0, 1, 2, 3]), SCORES, 1, {}, 0.5),
+        (np.array([0, 1, 2, 3]), SCORES, 2, {}, 0.75),
+        (np.array([0, 1, 2, 3]), SCORES.astype(np.float64), 4, {}, 1.0),
+        (
+            np.array([10, 11, 12, 13], dtype=np.uint8),
+            SCORES,
+            2,
+            {"labels": [10, 11, 12, 13]},
+            0.75,
+        ),  # labels
+        (np.array([2, 1, 2, 3]), SCORES, 2, {}, 0.75),  # ties favour the true class
+    ],
+)
+def test_top_k_accuracy(y_true, scores, k, kwargs, expected):
+    assert np.isclose(fast_stats.top_k_accuracy(y_true, scores, k, **kwargs), expected)
+
+
+@pytest.mark.parametrize(
+    "y_true,scores,k,kwargs,expected",
+    [
+        (np.array([0, 1, 2, 3]), SCORES, 1, {}, np.array([1.0, 1.0, 0.0, 0.0])),
+        (np.array([0, 0, 2, 2]), SCORES, 2, {}, np.array([1.0, np.nan, 0.5, np.nan])),
+        (
+            np.array([0, 0, 2, 2]),
+            SCORES,
+            2,
+            {"zero_division": "zero"},
+            np.array([1.0, 0.0, 0.5, 0.0]),
+        ),
+    ],
+)
+def test_top_k_recall(y_true, scores, k, kwargs, expected):
+    assert np.allclose(
+        fast_stats.top_k_recall(y_true, scores, k, **kwargs), expected, equal_nan=True
+    )
+
+
+def test_top_k_errors():
+    with pytest.raises(ShapeError):
+        fast_stats.top_k_accuracy(np.array([0, 1]), SCORES, 1)
+    with pytest.raises(ValueError):
+        fast_stats.top_k_accuracy(np.array([0, 1, 2, 3]), SCORES, 0)
+    with pytest.raises(ValueError):
+        fast_stats.top_k_accuracy(np.array([0, 1, 2, 3]), SCORES, 1, labels=[0, 1])
+    with pytest.raises(ValueError):
+        fast_stats.top_k_accuracy(np.array([0, 1, 2, 7]), SCORES, 1)
+    with pytest.raises(ValueError):
+        fast_stats.top_k_recall(np.array([0, 1, 2, 3]), SCORES, 1, labels=[0, 1, 2, 5])